@@ -0,0 +1,161 @@
+//! Estimates what each game a hand could announce is worth.
+
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::deck::{Card, Deck};
+use crate::game::{Game, PlayerView, Seat, Tariff};
+use crate::gamemode::Announcement;
+use crate::player::{NpcPlayer, Player, PlayerError};
+
+/// Settings for an [`Advisor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvisorConfig {
+    /// Number of deals simulated per announcement.
+    pub samples: usize,
+    /// Seed for dealing the other hands; `None` seeds from the operating system.
+    pub seed: Option<u64>,
+    pub tariff: Tariff,
+}
+
+impl Default for AdvisorConfig {
+    fn default() -> Self {
+        Self { samples: 200, seed: None, tariff: Tariff::default() }
+    }
+}
+
+/// How one announcement fared in the simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Evaluation {
+    pub announcement: Announcement,
+    /// Share of simulated games the declaring side won.
+    pub win_probability: f64,
+    /// Average amount the advised seat won or paid per game.
+    pub expected_settlement: f64,
+}
+
+/// The advisor's recommendation for a hand.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Advice {
+    /// The game to announce, `None` to pass.
+    pub recommendation: Option<Announcement>,
+    /// Every option, most profitable first.
+    pub evaluations: Vec<Evaluation>,
+    /// A short summary for showing to the player.
+    pub explanation: String,
+}
+
+/// Builds the player used for a seat in the simulated games.
+pub type BotFactory = Box<dyn Fn(Seat) -> Box<dyn Player>>;
+
+/// Recommends an announcement by playing each option out against bots.
+///
+/// The other three hands are dealt at random from the cards the seat does
+/// not hold, and the same deals are used for every option so that they are
+/// compared fairly. Passing is valued at zero.
+pub struct Advisor {
+    pub config: AdvisorConfig,
+    bot: BotFactory,
+    rng: StdRng,
+}
+
+impl Advisor {
+    /// An advisor simulating every seat with [`NpcPlayer`].
+    pub fn new(config: AdvisorConfig) -> Self {
+        Self::with_bot(config, Box::new(|seat| Box::new(NpcPlayer::new(seat as u32, format!("NPC {}", seat)))))
+    }
+
+    pub fn with_bot(config: AdvisorConfig, bot: BotFactory) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self { config, bot, rng }
+    }
+
+    /// Simulates each of `options` with the seat of `view` as declarer.
+    ///
+    /// `view` is a view before the game, as passed to [`Player::bid`].
+    pub fn evaluate(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Vec<Evaluation>, PlayerError> {
+        let me = view.seat();
+        let leader = view.current_trick().leader();
+        let others: Vec<Card> = Deck::new().iter().copied().filter(|c| !view.hand().contains(c)).collect();
+
+        let deals: Vec<[Vec<Card>; 4]> = (0..self.config.samples.max(1))
+            .map(|_| {
+                let mut cards = others.clone();
+                cards.shuffle(&mut self.rng);
+                // Every other seat gets as many cards as it holds in the view
+                let mut pool = cards.into_iter();
+                std::array::from_fn(|s| {
+                    if s == me { view.hand().to_vec() } else { pool.by_ref().take(view.hand_size(s)).collect() }
+                })
+            })
+            .collect();
+
+        let mut evaluations = Vec::with_capacity(options.len());
+        for &announcement in options {
+            let (mut wins, mut total) = (0, 0);
+            for hands in &deals {
+                let game = self.simulate(announcement, hands.clone(), leader, me)?;
+                if game.outcome().is_some_and(|o| o.declarer_wins) {
+                    wins += 1;
+                }
+                total += game.settlement(&self.config.tariff).map_or(0, |s| s[me]);
+            }
+            evaluations.push(Evaluation {
+                announcement,
+                win_probability: wins as f64 / deals.len() as f64,
+                expected_settlement: total as f64 / deals.len() as f64,
+            });
+        }
+        evaluations.sort_by(|a, b| b.expected_settlement.total_cmp(&a.expected_settlement));
+        Ok(evaluations)
+    }
+
+    /// Evaluates `options` and recommends the most profitable one, or
+    /// passing if none is expected to win money.
+    pub fn advise(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Advice, PlayerError> {
+        let evaluations = self.evaluate(view, options)?;
+        let recommendation = evaluations.first().filter(|e| e.expected_settlement > 0.0).map(|e| e.announcement);
+
+        let mut explanation = match evaluations.first() {
+            Some(best) if recommendation.is_some() => format!(
+                "{} wins {:.0}% of {} simulated games and earns {:+.1} on average.",
+                best.announcement,
+                best.win_probability * 100.0,
+                self.config.samples.max(1),
+                best.expected_settlement,
+            ),
+            Some(_) => "No game is expected to pay off; pass.".to_string(),
+            None => "Nothing to announce; pass.".to_string(),
+        };
+        let skip = recommendation.is_some() as usize;
+        for e in evaluations.iter().skip(skip).take(2) {
+            write!(explanation, " {}: {:.0}%, {:+.1}.", e.announcement, e.win_probability * 100.0, e.expected_settlement)
+                .unwrap();
+        }
+
+        Ok(Advice { recommendation, evaluations, explanation })
+    }
+
+    fn simulate(&self, announcement: Announcement, hands: [Vec<Card>; 4], leader: Seat, declarer: Seat) -> Result<Game, PlayerError> {
+        let mut bots: Vec<Box<dyn Player>> = (0..4).map(|seat| (self.bot)(seat)).collect();
+        let mut game = Game::new(announcement, hands, leader, Some(declarer));
+        while let Some(seat) = game.current_player() {
+            let legal = game.legal_cards();
+            let card = bots[seat].play_card(&game.view(seat), &legal)?;
+            game.play(card).map_err(|_| PlayerError::IllegalCard(card))?;
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests;
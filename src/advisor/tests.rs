@@ -0,0 +1,67 @@
+use super::*;
+use crate::deck::{Rank, Suit};
+use crate::gamemode::{Gamemode, Ruleset, available_announcements};
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+fn advisor() -> Advisor {
+    Advisor::new(AdvisorConfig { samples: 20, seed: Some(3), ..AdvisorConfig::default() })
+}
+
+#[test]
+fn recommends_a_solo_with_a_strong_hand() {
+    let hand = [
+        card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Ober), card(Suit::Herz, Rank::Ober),
+        card(Suit::Eichel, Rank::Unter), card(Suit::Herz, Rank::Ass), card(Suit::Herz, Rank::Zehn),
+        card(Suit::Herz, Rank::Neun), card(Suit::Schell, Rank::Sieben),
+    ];
+    let view = PlayerView::before_game(1, &hand, 0, &[]);
+    let options = available_announcements(&hand, &Ruleset::default());
+
+    let advice = advisor().advise(&view, &options).unwrap();
+    assert_eq!(advice.recommendation, Some(Announcement::Game(Gamemode::Solo(Suit::Herz))));
+    assert_eq!(advice.evaluations.len(), options.len());
+    assert!(advice.evaluations[0].win_probability > 0.9);
+    assert!(advice.explanation.starts_with("Herz-Solo wins"));
+}
+
+#[test]
+fn advises_to_pass_with_a_weak_hand() {
+    let hand = [
+        card(Suit::Schell, Rank::Sieben), card(Suit::Schell, Rank::Acht), card(Suit::Gras, Rank::Sieben),
+        card(Suit::Gras, Rank::Acht), card(Suit::Eichel, Rank::Sieben), card(Suit::Eichel, Rank::Neun),
+        card(Suit::Herz, Rank::Sieben), card(Suit::Schell, Rank::Koenig),
+    ];
+    let view = PlayerView::before_game(0, &hand, 0, &[]);
+    let options = available_announcements(&hand, &Ruleset::default());
+
+    let advice = advisor().advise(&view, &options).unwrap();
+    assert_eq!(advice.recommendation, None);
+    assert!(advice.evaluations.iter().all(|e| e.expected_settlement <= 0.0));
+    assert!(advice.evaluations.windows(2).all(|w| w[0].expected_settlement >= w[1].expected_settlement));
+}
+
+#[test]
+fn same_seed_gives_same_evaluation() {
+    let hand = Deck::new().deal_4x8().unwrap()[2].clone();
+    let view = PlayerView::before_game(2, &hand, 1, &[]);
+    let options = available_announcements(&hand, &Ruleset::all());
+
+    assert_eq!(advisor().evaluate(&view, &options).unwrap(), advisor().evaluate(&view, &options).unwrap());
+}
+
+#[test]
+fn deals_the_other_seats_hands_of_the_same_size() {
+    let hand = [
+        card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Ober), card(Suit::Herz, Rank::Ass),
+        card(Suit::Herz, Rank::Zehn),
+    ];
+    let view = PlayerView::before_game(0, &hand, 0, &[]);
+    let solo = Announcement::Game(Gamemode::Solo(Suit::Herz));
+
+    let evaluations = advisor().evaluate(&view, &[solo]).unwrap();
+    assert_eq!(evaluations.len(), 1);
+    assert!((0.0..=1.0).contains(&evaluations[0].win_probability));
+}
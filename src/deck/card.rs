@@ -1,15 +1,38 @@
 use std::fmt;
 
+use strum::IntoEnumIterator;
+
 use crate::deck::{Suit, Rank};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
 }
 
+impl Card {
+    /// Position of the card in `0..32`: eight per suit in the order
+    /// Eichel, Gras, Herz, Schell, each from Ass down to Sieben, as in
+    /// `Deck::new`. The numbering never changes.
+    pub fn index(&self) -> usize {
+        self.suit as usize * 8 + self.rank as usize
+    }
+
+    /// The card at `index`, `None` unless it is below 32.
+    pub fn from_index(index: usize) -> Option<Card> {
+        let suit = Suit::iter().nth(index / 8)?;
+        let rank = Rank::iter().nth(index % 8)?;
+        Some(Card { suit, rank })
+    }
+}
+
+/// "Eichel Ober", or in short notation with `{:#}`: "EO".
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.suit, self.rank)
+        if f.alternate() {
+            write!(f, "{:#}{:#}", self.suit, self.rank)
+        } else {
+            write!(f, "{} {}", self.suit, self.rank)
+        }
     }
 }
\ No newline at end of file
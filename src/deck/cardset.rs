@@ -0,0 +1,250 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use crate::deck::{Card, Rank, Suit};
+
+// Points of every combination of ranks within one suit, indexed by the
+// suit's byte of a `CardSet`
+const SUIT_POINTS: [u8; 256] = {
+    // In `Card::index` order
+    let ranks = [Rank::Ass, Rank::Zehn, Rank::Koenig, Rank::Ober, Rank::Unter, Rank::Neun, Rank::Acht, Rank::Sieben];
+    let mut table = [0; 256];
+    let mut bits = 0;
+    while bits < 256 {
+        let mut rank = 0;
+        while rank < 8 {
+            if bits & 1 << rank != 0 {
+                table[bits] += ranks[rank].points();
+            }
+            rank += 1;
+        }
+        bits += 1;
+    }
+    table
+};
+
+/// A set of cards stored as one bit per [`Card::index`].
+///
+/// Insertion, removal and lookup are single bit operations, and the set
+/// operators `|`, `&`, `-` and `!` work on whole sets.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u32);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const ALL: CardSet = CardSet(u32::MAX);
+
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    pub fn from_bits(bits: u32) -> Self {
+        CardSet(bits)
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// All eight cards of `suit`, trumps included.
+    pub fn suit(suit: Suit) -> Self {
+        CardSet(0xff << (suit as u32 * 8))
+    }
+
+    /// The four cards of `rank`.
+    pub fn rank(rank: Rank) -> Self {
+        CardSet(0x0101_0101 << rank as u32)
+    }
+
+    /// Adds `card`, returning whether it was new.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let new = !self.contains(card);
+        self.0 |= bit(card);
+        new
+    }
+
+    /// Removes `card`, returning whether it was there.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !bit(card);
+        present
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & bit(card) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    pub fn is_subset(&self, other: CardSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Total points of the cards, by table lookup.
+    pub fn points(&self) -> u8 {
+        self.0.to_le_bytes().iter().map(|&byte| SUIT_POINTS[byte as usize]).sum()
+    }
+
+    /// The cards in index order.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    /// The cards in the order they appear in `order`, e.g. a trump order or
+    /// a sorted hand; cards missing from `order` are left out.
+    pub fn iter_in<'a>(&self, order: &'a [Card]) -> impl Iterator<Item = Card> + 'a {
+        let set = *self;
+        order.iter().copied().filter(move |&c| set.contains(c))
+    }
+
+    pub fn to_vec(&self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+fn bit(card: Card) -> u32 {
+    1 << card.index()
+}
+
+/// Iterator over a [`CardSet`] in index order.
+pub struct Iter(u32);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Card::from_index(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = &'a Card>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        cards.iter().collect()
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+    fn not(self) -> CardSet {
+        CardSet(!self.0)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, other: CardSet) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, other: CardSet) {
+        self.0 &= other.0;
+    }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, other: CardSet) {
+        self.0 &= !other.0;
+    }
+}
+
+/// The cards in short notation, e.g. "EO GU HA".
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, card) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{:#}", card)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CardSet[{}]", self)
+    }
+}
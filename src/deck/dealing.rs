@@ -0,0 +1,123 @@
+use rand::RngCore;
+
+use crate::deck::{Card, Deck, below};
+use crate::game::{Seat, assert_seat, next_seat};
+
+/// Fewest cards either pile keeps when cutting at random.
+pub const MIN_CUT: usize = 3;
+
+/// How the cards go round the table: the size of the packet each seat gets
+/// per round, e.g. 4 + 4 or 3 + 2 + 3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dealing {
+    packets: Vec<usize>,
+}
+
+impl Dealing {
+    pub fn new(packets: Vec<usize>) -> Self {
+        Self { packets }
+    }
+
+    /// Two rounds of four cards, the usual way.
+    pub fn in_fours() -> Self {
+        Self::new(vec![4, 4])
+    }
+
+    /// Rounds of three, two and three cards.
+    pub fn three_two_three() -> Self {
+        Self::new(vec![3, 2, 3])
+    }
+
+    pub fn packets(&self) -> &[usize] {
+        &self.packets
+    }
+
+    /// Cards each seat ends up with.
+    pub fn hand_size(&self) -> usize {
+        self.packets.iter().sum()
+    }
+}
+
+impl Default for Dealing {
+    fn default() -> Self {
+        Self::in_fours()
+    }
+}
+
+/// One card handed out by [`Deck::deal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DealtCard {
+    pub card: Card,
+    pub seat: Seat,
+    /// Number of the packet among all packets dealt, from 0.
+    pub packet: usize,
+}
+
+/// The hands of a deal and every card in the order it was dealt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DealLog {
+    pub hands: [Vec<Card>; 4],
+    pub cards: Vec<DealtCard>,
+}
+
+impl Deck {
+    /// Abheben: lifts the top `at` cards off and puts them underneath.
+    ///
+    /// Returns false and leaves the deck alone if it has fewer than `at`
+    /// cards.
+    pub fn cut(&mut self, at: usize) -> bool {
+        if at > self.cards.len() {
+            return false;
+        }
+        // The top of the deck is the end `draw` takes from
+        self.cards.rotate_right(at);
+        true
+    }
+
+    /// Cuts at a random position that leaves at least [`MIN_CUT`] cards on
+    /// either pile and returns it; a deck too small for that is not cut.
+    pub fn cut_with(&mut self, rng: &mut impl RngCore) -> usize {
+        let len = self.cards.len();
+        if len < 2 * MIN_CUT {
+            return 0;
+        }
+        let at = MIN_CUT + below(rng, (len - 2 * MIN_CUT + 1) as u32) as usize;
+        self.cut(at);
+        at
+    }
+
+    /// Deals from the top as at the table: packet after packet, starting
+    /// with the seat after `dealer` and going round to the dealer.
+    ///
+    /// `None` if the deck holds fewer than four hands' worth of cards.
+    ///
+    /// # Panics
+    ///
+    /// If `dealer` is not a seat below 4.
+    pub fn deal(&mut self, dealer: Seat, dealing: &Dealing) -> Option<DealLog> {
+        assert_seat(dealer);
+        let size = dealing.hand_size();
+        if self.cards.len() < 4 * size {
+            return None;
+        }
+        let mut hands: [Vec<Card>; 4] = Default::default();
+        let mut cards = Vec::with_capacity(4 * size);
+        let mut packet = 0;
+        for &count in dealing.packets() {
+            let mut seat = dealer;
+            for _ in 0..4 {
+                seat = next_seat(seat);
+                for _ in 0..count {
+                    let card = self.draw()?;
+                    hands[seat].push(card);
+                    cards.push(DealtCard { card, seat, packet });
+                }
+                packet += 1;
+            }
+        }
+        Some(DealLog { hands, cards })
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use rand_chacha::ChaCha8Rng;
+
+use crate::deck::{Card, CardSet, Deck, seeded_rng};
+use crate::game::{Seat, assert_seat};
+use crate::gamemode::Gamemode;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+    /// The required and forbidden cards cannot all be met by any deal.
+    Contradiction,
+    /// No deal passed every filter within the given number of attempts.
+    NotFound { attempts: usize },
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::Contradiction => write!(f, "the constraints contradict each other"),
+            GenerateError::NotFound { attempts } => write!(f, "no matching deal found in {} attempts", attempts),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+type Filter = Box<dyn Fn(&[CardSet; 4]) -> bool>;
+
+/// Random deals that meet a set of constraints, for scenarios and training.
+///
+/// Cards a seat must hold are placed first and the rest of a shuffled
+/// [`Deck`] is dealt around them; deals that give a seat a card it must
+/// lack or fail a filter are drawn again. Every matching deal is therefore
+/// equally likely. The same seed and constraints give the same deals.
+///
+/// ```
+/// use schafkopf_logic::deck::{Card, DealGenerator, Rank, Suit};
+/// use schafkopf_logic::gamemode::Gamemode;
+///
+/// let solo = Gamemode::Solo(Suit::Herz);
+/// let hands = DealGenerator::new(7)
+///     .min_trumps(1, &solo, 6)
+///     .holds(2, Card { suit: Suit::Gras, rank: Rank::Ass })
+///     .generate()
+///     .unwrap();
+/// assert!(hands[1].iter().filter(|c| solo.is_trump(c)).count() >= 6);
+/// ```
+pub struct DealGenerator {
+    rng: ChaCha8Rng,
+    holds: [CardSet; 4],
+    lacks: [CardSet; 4],
+    filters: Vec<Filter>,
+    attempts: usize,
+}
+
+impl DealGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: seeded_rng(seed),
+            holds: [CardSet::EMPTY; 4],
+            lacks: [CardSet::EMPTY; 4],
+            filters: Vec::new(),
+            attempts: 100_000,
+        }
+    }
+
+    /// `seat` gets `card`.
+    ///
+    /// # Panics
+    ///
+    /// If `seat` is not below 4, as in all methods taking a seat.
+    pub fn holds(mut self, seat: Seat, card: Card) -> Self {
+        assert_seat(seat);
+        self.holds[seat].insert(card);
+        self
+    }
+
+    /// `seat` does not get `card`.
+    pub fn lacks(mut self, seat: Seat, card: Card) -> Self {
+        assert_seat(seat);
+        self.lacks[seat].insert(card);
+        self
+    }
+
+    /// Only deals for which `filter` returns true; the hands are given by seat.
+    pub fn filter(mut self, filter: impl Fn(&[CardSet; 4]) -> bool + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// `seat` gets at least `count` trumps of `mode`.
+    pub fn min_trumps(self, seat: Seat, mode: &Gamemode, count: usize) -> Self {
+        assert_seat(seat);
+        let trumps = mode.trump_set();
+        self.filter(move |hands| (hands[seat] & trumps).len() >= count)
+    }
+
+    /// `seat` holds exactly `count` Laufende of `mode`: the `count` highest
+    /// trumps but not the next one.
+    pub fn laufende(mut self, seat: Seat, mode: &Gamemode, count: usize) -> Self {
+        assert_seat(seat);
+        let trumps = mode.trumps();
+        for &card in trumps.iter().take(count) {
+            self = self.holds(seat, card);
+        }
+        match trumps.get(count) {
+            Some(&next) => self.lacks(seat, next),
+            None => self,
+        }
+    }
+
+    /// Gives up after this many deals were drawn in vain (100 000 by default).
+    pub fn attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Draws the next matching deal, each hand in deck order.
+    pub fn generate(&mut self) -> Result<[Vec<Card>; 4], GenerateError> {
+        let fixed = self.holds.iter().fold(CardSet::EMPTY, |all, &h| all | h);
+        let placed: usize = self.holds.iter().map(|h| h.len()).sum();
+        let contradiction = placed != fixed.len()
+            || self.holds.iter().any(|h| h.len() > 8)
+            || (0..4).any(|s| !(self.holds[s] & self.lacks[s]).is_empty());
+        if contradiction {
+            return Err(GenerateError::Contradiction);
+        }
+
+        for _ in 0..self.attempts {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut self.rng);
+            let mut hands = self.holds;
+            let mut seat = 0;
+            for &card in deck.iter().filter(|&&c| !fixed.contains(c)) {
+                while hands[seat].len() == 8 {
+                    seat += 1;
+                }
+                hands[seat].insert(card);
+            }
+
+            let allowed = (0..4).all(|s| (hands[s] & self.lacks[s]).is_empty());
+            if allowed && self.filters.iter().all(|f| f(&hands)) {
+                return Ok(hands.map(|h| h.to_vec()));
+            }
+        }
+        Err(GenerateError::NotFound { attempts: self.attempts })
+    }
+}
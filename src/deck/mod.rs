@@ -7,10 +7,34 @@ pub use suit::Suit;
 mod card;
 pub use card::Card;
 
+mod cardset;
+pub use cardset::CardSet;
+
+mod dealing;
+pub use dealing::{DealLog, DealtCard, Dealing, MIN_CUT};
+
+mod generator;
+pub use generator::{DealGenerator, GenerateError};
+
+mod notation;
+pub use notation::{Code, ParseCardError, format_cards, parse_cards};
+
+mod numbering;
+pub use numbering::{DEAL_COUNT, deal_from_index, deal_index};
+
+mod pattern;
+pub use pattern::Pattern;
+
+#[cfg(feature = "serde")]
+mod serialize;
+
+mod validate;
+pub use validate::DeckError;
+
 use strum::IntoEnumIterator;
 
-use rand::seq::SliceRandom;
-use rand::rng;
+use rand::{RngCore, SeedableRng, rng};
+use rand_chacha::ChaCha8Rng;
 
 pub struct Deck {
     cards: Vec<Card>,
@@ -30,27 +54,64 @@ impl Deck {
         Self { cards }
     }
 
+    /// A deck shuffled from `seed`.
+    ///
+    /// The same seed gives the same order on every platform and in every
+    /// version of this crate: the shuffle is driven by ChaCha8 keyed with
+    /// the little-endian bytes of `seed`.
+    pub fn shuffled(seed: u64) -> Self {
+        let mut deck = Self::new();
+        deck.shuffle_with(&mut seeded_rng(seed));
+        deck
+    }
+
     pub fn draw(&mut self) -> Option<Card> {
         self.cards.pop()
     }
 
     pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut rng());
+        self.shuffle_with(&mut rng());
     }
 
-    pub fn deal_4x8(&mut self) -> Option<[Vec<Card>; 4]> {
-        if self.cards.len() < 32 { return None; }
-        let mut hands = [Vec::with_capacity(8), Vec::with_capacity(8),
-                         Vec::with_capacity(8), Vec::with_capacity(8)];
-        for _ in 0..8 {
-            for h in 0..4 {
-                hands[h].push(self.draw()?);
-            }
+    /// Shuffles with the given generator (Fisher-Yates).
+    ///
+    /// The order only depends on the numbers `rng` produces, not on the
+    /// version of `rand`.
+    pub fn shuffle_with(&mut self, rng: &mut impl RngCore) {
+        for i in (1..self.cards.len()).rev() {
+            let j = below(rng, i as u32 + 1) as usize;
+            self.cards.swap(i, j);
         }
-        Some(hands)
+    }
+
+    /// Deals eight cards to every seat one at a time, seat 0 first; see
+    /// [`Deck::deal`] for dealing in packets.
+    pub fn deal_4x8(&mut self) -> Option<[Vec<Card>; 4]> {
+        self.deal(3, &Dealing::new(vec![1; 8])).map(|log| log.hands)
     }
 
     pub fn iter(&self) -> impl Iterator<Item=&Card> {
         self.cards.iter()
     }
-}
\ No newline at end of file
+}
+
+// The portable generator behind `Deck::shuffled`
+pub(crate) fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    let mut key = [0; 32];
+    key[..8].copy_from_slice(&seed.to_le_bytes());
+    ChaCha8Rng::from_seed(key)
+}
+
+// Uniform number in `0..bound`, rejecting the values that would bias it
+fn below(rng: &mut impl RngCore, bound: u32) -> u32 {
+    let limit = u32::MAX - u32::MAX % bound;
+    loop {
+        let x = rng.next_u32();
+        if x < limit {
+            return x % bound;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
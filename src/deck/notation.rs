@@ -0,0 +1,160 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::deck::{Card, Rank, Suit};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    /// There was nothing to read.
+    Empty,
+    UnknownSuit(String),
+    UnknownRank(String),
+    /// The card at `position` (counting from 1) of a list could not be read.
+    InList { position: usize, error: Box<ParseCardError> },
+    /// A list names the same card twice.
+    Duplicate(Card),
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::Empty => write!(f, "no card given"),
+            ParseCardError::UnknownSuit(s) => write!(f, "unknown suit {:?}, expected E, G, H or S", s),
+            ParseCardError::UnknownRank(s) => write!(f, "unknown rank {:?}, expected A, 10, K, O, U, 9, 8 or 7", s),
+            ParseCardError::InList { position, error } => write!(f, "card {}: {}", position, error),
+            ParseCardError::Duplicate(card) => write!(f, "{} is listed twice", card.code()),
+        }
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+impl Suit {
+    /// Letter of the suit in the short notation: E, G, H or S.
+    pub fn letter(&self) -> char {
+        match self {
+            Suit::Eichel    => 'E',
+            Suit::Gras      => 'G',
+            Suit::Herz      => 'H',
+            Suit::Schell    => 'S',
+        }
+    }
+}
+
+impl Rank {
+    /// Symbol of the rank in the short notation: A, 10, K, O, U, 9, 8 or 7.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Rank::Ass       => "A",
+            Rank::Zehn      => "10",
+            Rank::Koenig    => "K",
+            Rank::Ober      => "O",
+            Rank::Unter     => "U",
+            Rank::Neun      => "9",
+            Rank::Acht      => "8",
+            Rank::Sieben    => "7",
+        }
+    }
+}
+
+impl Card {
+    /// The card in short notation, e.g. "EO", "H10" or "S7"; the same as
+    /// formatting it with `{:#}`.
+    pub fn code(&self) -> Code {
+        Code(*self)
+    }
+}
+
+/// A card shown in short notation, see [`Card::code`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code(Card);
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self.0)
+    }
+}
+
+/// Reads a suit by its letter or name, ignoring case ("H", "Herz").
+///
+/// The common aliases Grün, Laub and Blatt for Gras, Rot for Herz and
+/// Schellen for Schell are understood as well.
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" => Err(ParseCardError::Empty),
+            "e" | "eichel" => Ok(Suit::Eichel),
+            "g" | "gras" | "grün" | "gruen" | "laub" | "blatt" => Ok(Suit::Gras),
+            "h" | "herz" | "rot" => Ok(Suit::Herz),
+            "s" | "schell" | "schellen" => Ok(Suit::Schell),
+            _ => Err(ParseCardError::UnknownSuit(s.trim().to_string())),
+        }
+    }
+}
+
+/// Reads a rank by its symbol or name, ignoring case ("O", "Ober"); the Ass
+/// may also be called Sau and the Zehn Z.
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" => Err(ParseCardError::Empty),
+            "a" | "ass" | "sau" => Ok(Rank::Ass),
+            "10" | "z" | "zehn" => Ok(Rank::Zehn),
+            "k" | "könig" | "koenig" => Ok(Rank::Koenig),
+            "o" | "ober" => Ok(Rank::Ober),
+            "u" | "unter" => Ok(Rank::Unter),
+            "9" | "neun" => Ok(Rank::Neun),
+            "8" | "acht" => Ok(Rank::Acht),
+            "7" | "sieben" => Ok(Rank::Sieben),
+            _ => Err(ParseCardError::UnknownRank(s.trim().to_string())),
+        }
+    }
+}
+
+/// Reads a card in short notation ("HO", "g10") or as suit and rank
+/// separated by a space ("Gras Sau", "herz 10").
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (suit, rank) = match s.split_once(char::is_whitespace) {
+            Some(parts) => parts,
+            None => s.split_at(s.chars().next().ok_or(ParseCardError::Empty)?.len_utf8()),
+        };
+        Ok(Card { suit: suit.parse()?, rank: rank.parse()? })
+    }
+}
+
+/// Reads a hand or trick written as short codes separated by spaces or
+/// commas, e.g. "EO GU HA H10".
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    parse_list(s, str::parse)
+}
+
+// Reads a list of cards separated by spaces or commas with `parse`
+pub(crate) fn parse_list(
+    s: &str,
+    parse: impl Fn(&str) -> Result<Card, ParseCardError>,
+) -> Result<Vec<Card>, ParseCardError> {
+    let mut cards = Vec::new();
+    for (i, token) in s.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()).enumerate() {
+        let card = parse(token)
+            .map_err(|error| ParseCardError::InList { position: i + 1, error: Box::new(error) })?;
+        if cards.contains(&card) {
+            return Err(ParseCardError::Duplicate(card));
+        }
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+/// Writes `cards` in short notation separated by spaces; the inverse of
+/// [`parse_cards`].
+pub fn format_cards(cards: &[Card]) -> String {
+    cards.iter().map(|c| c.code().to_string()).collect::<Vec<_>>().join(" ")
+}
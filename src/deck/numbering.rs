@@ -0,0 +1,85 @@
+use crate::deck::Card;
+
+/// Number of distinct deals of 32 cards into four hands of eight,
+/// 32! / (8!)^4.
+pub const DEAL_COUNT: u64 = 99_561_092_450_391_000;
+
+/// The number of `hands` in `0..DEAL_COUNT`.
+///
+/// The order of the cards within a hand does not matter. Returns `None`
+/// unless the hands hold eight cards each and every card exactly once.
+/// A uniformly random number below [`DEAL_COUNT`] passed to
+/// [`deal_from_index`] gives a uniformly random deal.
+pub fn deal_index(hands: &[Vec<Card>; 4]) -> Option<u64> {
+    let mut owner = [None; 32];
+    for (seat, hand) in hands.iter().enumerate() {
+        if hand.len() != 8 {
+            return None;
+        }
+        for card in hand {
+            let slot = &mut owner[card.index()];
+            if slot.is_some() {
+                return None;
+            }
+            *slot = Some(seat);
+        }
+    }
+
+    let mut left = [8; 4];
+    let mut index = 0;
+    for seat in owner {
+        let seat = seat?;
+        // Deals that give this card to an earlier seat come first
+        for earlier in 0..seat {
+            if left[earlier] > 0 {
+                left[earlier] -= 1;
+                index += arrangements(&left);
+                left[earlier] += 1;
+            }
+        }
+        left[seat] -= 1;
+    }
+    Some(index)
+}
+
+/// The deal numbered `index` by [`deal_index`], each hand in deck order.
+///
+/// `None` if `index` is not below [`DEAL_COUNT`].
+pub fn deal_from_index(mut index: u64) -> Option<[Vec<Card>; 4]> {
+    if index >= DEAL_COUNT {
+        return None;
+    }
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    let mut left = [8; 4];
+    for card in (0..32).filter_map(Card::from_index) {
+        for seat in 0..4 {
+            if left[seat] == 0 {
+                continue;
+            }
+            left[seat] -= 1;
+            let count = arrangements(&left);
+            if index < count {
+                hands[seat].push(card);
+                break;
+            }
+            index -= count;
+            left[seat] += 1;
+        }
+    }
+    Some(hands)
+}
+
+// Ways to deal the remaining cards when each seat still gets `left` of them
+fn arrangements(left: &[u64; 4]) -> u64 {
+    let mut n = 0;
+    let mut ways = 1;
+    for &k in left {
+        n += k;
+        ways *= binomial(n, k);
+    }
+    ways
+}
+
+fn binomial(n: u64, k: u64) -> u64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
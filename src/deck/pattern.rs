@@ -0,0 +1,137 @@
+use crate::deck::notation::parse_list;
+use crate::deck::{Card, ParseCardError, Rank, Suit};
+use crate::gamemode::Gamemode;
+
+/// The faces the cards are shown with.
+///
+/// The French pattern maps Eichel, Gras, Herz and Schell to Kreuz, Pik,
+/// Herz and Karo, and Ober and Unter to Dame and Bube. It only changes how
+/// cards are named, read and sorted; the game modes keep their rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pattern {
+    #[default]
+    German,
+    French,
+}
+
+impl Pattern {
+    pub fn suit_name(self, suit: Suit) -> &'static str {
+        match (self, suit) {
+            (Pattern::German, _)             => suit.name(),
+            (Pattern::French, Suit::Eichel)  => "Kreuz",
+            (Pattern::French, Suit::Gras)    => "Pik",
+            (Pattern::French, Suit::Herz)    => "Herz",
+            (Pattern::French, Suit::Schell)  => "Karo",
+        }
+    }
+
+    pub fn rank_name(self, rank: Rank) -> &'static str {
+        match (self, rank) {
+            (Pattern::French, Rank::Ober)    => "Dame",
+            (Pattern::French, Rank::Unter)   => "Bube",
+            _                                => rank.name(),
+        }
+    }
+
+    /// The card's full name, e.g. "Kreuz Dame".
+    pub fn card_name(self, card: Card) -> String {
+        format!("{} {}", self.suit_name(card.suit), self.rank_name(card.rank))
+    }
+
+    /// The card in short notation: "EO" in the German pattern, "♣D" in the
+    /// French one, which writes suits as ♣ ♠ ♥ ♦ and ranks as A, 10, K, D,
+    /// B, 9, 8, 7.
+    pub fn code(self, card: Card) -> String {
+        match self {
+            Pattern::German => card.code().to_string(),
+            Pattern::French => {
+                let rank = match card.rank {
+                    Rank::Ober  => "D",
+                    Rank::Unter => "B",
+                    rank        => rank.symbol(),
+                };
+                format!("{}{}", french_symbol(card.suit), rank)
+            }
+        }
+    }
+
+    /// Writes `cards` in short notation separated by spaces.
+    pub fn format_cards(self, cards: &[Card]) -> String {
+        cards.iter().map(|&c| self.code(c)).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Reads a card by code or name. The French pattern understands "♣D",
+    /// "Kreuz Dame" and the aliases Treff and Schippe, and falls back to
+    /// the German names, so "Karo Ober" works too.
+    pub fn parse_card(self, s: &str) -> Result<Card, ParseCardError> {
+        match self {
+            Pattern::German => s.parse(),
+            Pattern::French => {
+                let s = s.trim();
+                let (suit, rank) = match s.split_once(char::is_whitespace) {
+                    Some(parts) => parts,
+                    None => s.split_at(s.chars().next().ok_or(ParseCardError::Empty)?.len_utf8()),
+                };
+                let suit = french_suit(suit).map_or_else(|| suit.parse(), Ok)?;
+                let rank = french_rank(rank).map_or_else(|| rank.parse(), Ok)?;
+                Ok(Card { suit, rank })
+            }
+        }
+    }
+
+    /// Reads a list of cards separated by spaces or commas, like
+    /// [`parse_cards`](crate::deck::parse_cards).
+    pub fn parse_cards(self, s: &str) -> Result<Vec<Card>, ParseCardError> {
+        parse_list(s, |token| self.parse_card(token))
+    }
+
+    /// Sorts `hand` for display under `mode`: trumps first as in
+    /// [`Gamemode::sort_hand`], then the other suits. French hands
+    /// alternate black and red: Kreuz, Herz, Pik, Karo.
+    pub fn sort_hand(self, mode: &Gamemode, hand: &mut [Card]) {
+        mode.sort_hand(hand);
+        if self == Pattern::French {
+            let trumps = mode.trump_set();
+            hand.sort_by_key(|&c| {
+                if trumps.contains(c) {
+                    0
+                } else {
+                    match c.suit {
+                        Suit::Eichel    => 1,
+                        Suit::Herz      => 2,
+                        Suit::Gras      => 3,
+                        Suit::Schell    => 4,
+                    }
+                }
+            });
+        }
+    }
+}
+
+fn french_symbol(suit: Suit) -> char {
+    match suit {
+        Suit::Eichel    => '♣',
+        Suit::Gras      => '♠',
+        Suit::Herz      => '♥',
+        Suit::Schell    => '♦',
+    }
+}
+
+fn french_suit(s: &str) -> Option<Suit> {
+    match s.trim().to_lowercase().as_str() {
+        "♣" | "kreuz" | "treff" => Some(Suit::Eichel),
+        "♠" | "pik" | "schippe" => Some(Suit::Gras),
+        "♥" => Some(Suit::Herz),
+        "♦" | "karo" => Some(Suit::Schell),
+        _ => None,
+    }
+}
+
+fn french_rank(s: &str) -> Option<Rank> {
+    match s.trim().to_lowercase().as_str() {
+        "d" | "dame" => Some(Rank::Ober),
+        "b" | "bube" => Some(Rank::Unter),
+        _ => None,
+    }
+}
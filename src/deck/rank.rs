@@ -1,7 +1,7 @@
 use std::fmt;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
 pub enum Rank {
     Ass,
     Zehn,
@@ -14,21 +14,22 @@ pub enum Rank {
 }
 
 impl Rank {
-    pub fn points(&self) -> u8 {
+    /// Augen of the rank: Ass 11, Zehn 10, König 4, Ober 3, Unter 2, so a
+    /// deck holds 120.
+    pub const fn points(&self) -> u8 {
         match self {
             Rank::Ass       => 11,
             Rank::Zehn      => 10,
             Rank::Koenig    =>  4,
             Rank::Ober      =>  3,
-            Rank::Unter     =>  3,
+            Rank::Unter     =>  2,
             _               =>  0
         }
     }
-}
 
-impl fmt::Display for Rank {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = match self {
+    // The German name that Display writes
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
             Rank::Ass       => "Ass",
             Rank::Zehn      => "Zehn",
             Rank::Koenig    => "König",
@@ -37,7 +38,16 @@ impl fmt::Display for Rank {
             Rank::Neun      => "Neun",
             Rank::Acht      => "Acht",
             Rank::Sieben    => "Sieben",
-        };
-        write!(f, "{}", name)
+        }
+    }
+}
+
+/// The rank's name, or its symbol with `{:#}`.
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return f.write_str(self.symbol());
+        }
+        f.write_str(self.name())
     }
 }
\ No newline at end of file
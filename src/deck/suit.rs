@@ -1,7 +1,7 @@
 use std::fmt;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
 pub enum Suit {
     Eichel,
     Gras,
@@ -9,14 +9,24 @@ pub enum Suit {
     Schell,
 }
 
-impl fmt::Display for Suit {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = match self {
+impl Suit {
+    // The German name that Display writes
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
             Suit::Eichel    => "Eichel",
             Suit::Gras      => "Gras",
             Suit::Herz      => "Herz",
             Suit::Schell    => "Schell",
-        };
-        write!(f, "{}", name)
+        }
+    }
+}
+
+/// The suit's name, or its letter with `{:#}`.
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.letter());
+        }
+        f.write_str(self.name())
     }
 }
\ No newline at end of file
@@ -0,0 +1,334 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::*;
+
+#[test]
+fn a_deal_is_worth_120_points() {
+    let total: u32 = Deck::new().iter().map(|c| c.rank.points() as u32).sum();
+    assert_eq!(total, 120);
+}
+
+#[test]
+fn rank_points_follow_the_augen() {
+    let points: Vec<u8> = Rank::iter().map(|r| r.points()).collect();
+    assert_eq!(points, [11, 10, 4, 3, 2, 0, 0, 0]);
+    // The Unter counts 2, so each suit holds 30 Augen
+    assert_eq!(points.iter().map(|&p| p as u32).sum::<u32>(), 30);
+}
+
+#[test]
+fn same_seed_same_deal() {
+    let a: Vec<Card> = Deck::shuffled(42).iter().copied().collect();
+    let b: Vec<Card> = Deck::shuffled(42).iter().copied().collect();
+    let c: Vec<Card> = Deck::shuffled(43).iter().copied().collect();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let mut sorted = a.clone();
+    sorted.sort_by_key(|c| (c.suit, c.rank));
+    assert_eq!(sorted, Deck::new().iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn seeded_deal_is_stable() {
+    // Pinned so that a change of the shuffle or RNG shows up here
+    let hands = Deck::shuffled(2024).deal_4x8().unwrap();
+    let first: Vec<String> = hands[0].iter().map(|c| c.to_string()).collect();
+    assert_eq!(first, [
+        "Gras Ass", "Gras Zehn", "Herz Unter", "Schell Unter", "Gras Sieben", "Gras Acht", "Schell Ober", "Herz Ober",
+    ]);
+}
+
+#[test]
+fn shuffle_with_uses_the_given_rng() {
+    let mut a = Deck::new();
+    let mut b = Deck::new();
+    a.shuffle_with(&mut StdRng::seed_from_u64(5));
+    b.shuffle_with(&mut StdRng::seed_from_u64(5));
+    assert!(a.iter().eq(b.iter()));
+    assert!(!a.iter().eq(Deck::new().iter()));
+}
+
+#[test]
+fn deal_numbers_round_trip() {
+    assert_eq!(deal_index(&deal_from_index(0).unwrap()), Some(0));
+    assert_eq!(deal_index(&deal_from_index(DEAL_COUNT - 1).unwrap()), Some(DEAL_COUNT - 1));
+    assert_eq!(deal_from_index(DEAL_COUNT), None);
+
+    for seed in 0..50 {
+        let hands = Deck::shuffled(seed).deal_4x8().unwrap();
+        let index = deal_index(&hands).unwrap();
+        let mut back = deal_from_index(index).unwrap();
+        let mut expected = hands;
+        for (a, b) in back.iter_mut().zip(expected.iter_mut()) {
+            a.sort_by_key(|c| (c.suit, c.rank));
+            b.sort_by_key(|c| (c.suit, c.rank));
+        }
+        assert_eq!(back, expected);
+    }
+}
+
+#[test]
+fn first_deal_hands_out_the_deck_in_order() {
+    let hands = deal_from_index(0).unwrap();
+    let cards: Vec<Card> = Deck::new().iter().copied().collect();
+    assert_eq!(hands[0], cards[..8]);
+    assert_eq!(hands[3], cards[24..]);
+}
+
+#[test]
+fn rejects_invalid_deals() {
+    let mut hands = deal_from_index(12345).unwrap();
+    hands[0].pop();
+    assert_eq!(deal_index(&hands), None);
+
+    let mut hands = deal_from_index(12345).unwrap();
+    hands[1][0] = hands[0][0];
+    assert_eq!(deal_index(&hands), None);
+}
+
+#[test]
+fn short_notation_round_trips() {
+    for card in Deck::new().iter() {
+        assert_eq!(card.code().to_string().parse::<Card>(), Ok(*card));
+        assert_eq!(card.to_string().parse::<Card>(), Ok(*card));
+    }
+    assert_eq!(Card { suit: Suit::Gras, rank: Rank::Zehn }.code().to_string(), "G10");
+    assert_eq!(format!("{:#}", Card { suit: Suit::Eichel, rank: Rank::Ober }), "EO");
+    assert_eq!(format!("{:#} {:#}", Suit::Schell, Rank::Zehn), "S 10");
+    assert_eq!("gras sau".parse::<Card>(), Ok(Card { suit: Suit::Gras, rank: Rank::Ass }));
+    assert_eq!("Schellen".parse::<Suit>(), Ok(Suit::Schell));
+    assert_eq!("k".parse::<Rank>(), Ok(Rank::Koenig));
+}
+
+#[test]
+fn parses_hands_and_tricks() {
+    let cards = parse_cards("EO GU, HA  h10").unwrap();
+    assert_eq!(format_cards(&cards), "EO GU HA H10");
+    assert_eq!(parse_cards(""), Ok(vec![]));
+}
+
+#[test]
+fn parse_errors_say_what_is_wrong() {
+    assert_eq!("".parse::<Card>(), Err(ParseCardError::Empty));
+    assert_eq!("X7".parse::<Card>(), Err(ParseCardError::UnknownSuit("X".to_string())));
+    assert_eq!("H11".parse::<Card>(), Err(ParseCardError::UnknownRank("11".to_string())));
+
+    let err = parse_cards("EO GU HX").unwrap_err();
+    assert_eq!(err.to_string(), "card 3: unknown rank \"X\", expected A, 10, K, O, U, 9, 8 or 7");
+    assert_eq!(parse_cards("EO EO"), Err(ParseCardError::Duplicate(Card { suit: Suit::Eichel, rank: Rank::Ober })));
+}
+
+#[test]
+fn card_index_follows_the_deck() {
+    for (i, card) in Deck::new().iter().enumerate() {
+        assert_eq!(card.index(), i);
+        assert_eq!(Card::from_index(i), Some(*card));
+    }
+    assert_eq!(Card::from_index(32), None);
+}
+
+#[test]
+fn card_set_operations() {
+    let hand: CardSet = parse_cards("EO GU HA H10 S7").unwrap().iter().collect();
+    assert_eq!(hand.len(), 5);
+    assert!(hand.contains("HA".parse().unwrap()));
+    assert_eq!(hand.points(), 3 + 2 + 11 + 10);
+    assert_eq!(hand.to_string(), "EO GU HA H10 S7");
+
+    let mut herz = hand & CardSet::suit(Suit::Herz);
+    assert_eq!(herz.to_string(), "HA H10");
+    assert!(herz.is_subset(hand));
+    assert!(!herz.insert("HA".parse().unwrap()));
+    assert!(herz.remove("H10".parse().unwrap()));
+    assert_eq!((hand - herz).len(), 4);
+    assert_eq!((!hand).len(), 27);
+    assert_eq!(CardSet::ALL.points(), 120);
+    assert_eq!(CardSet::rank(Rank::Ober).len(), 4);
+}
+
+#[test]
+fn card_set_iterates_in_a_given_order() {
+    use crate::gamemode::Gamemode;
+
+    let mode = Gamemode::Solo(Suit::Gras);
+    let hand: CardSet = parse_cards("G7 EO SU GA").unwrap().into_iter().collect();
+    let trumps = mode.trumps();
+    assert_eq!(format_cards(&hand.iter_in(&trumps).collect::<Vec<_>>()), "EO SU GA G7");
+
+    assert_eq!(mode.trump_set().len(), 14);
+    assert_eq!(mode.suit_set(Suit::Herz).to_string(), "HA H10 HK H9 H8 H7");
+    assert_eq!(hand & mode.trump_set(), hand);
+}
+
+#[test]
+fn generated_deals_meet_the_constraints() {
+    use crate::gamemode::Gamemode;
+
+    let mode = Gamemode::Solo(Suit::Eichel);
+    let ga: Card = "GA".parse().unwrap();
+    let mut generator = DealGenerator::new(3)
+        .laufende(0, &mode, 3)
+        .min_trumps(0, &mode, 5)
+        .holds(2, ga)
+        .lacks(1, "S10".parse().unwrap());
+    for _ in 0..20 {
+        let hands = generator.generate().unwrap();
+        assert!(hands.iter().all(|h| h.len() == 8));
+        assert_eq!(hands.iter().flatten().collect::<CardSet>(), CardSet::ALL);
+        let hand: CardSet = hands[0].iter().collect();
+        assert_eq!(format_cards(&hand.iter_in(&mode.trumps()).take(3).collect::<Vec<_>>()), "EO GO HO");
+        assert!(!hand.contains("SO".parse().unwrap()));
+        assert!((hand & mode.trump_set()).len() >= 5);
+        assert!(hands[2].contains(&ga));
+        assert!(!hands[1].contains(&"S10".parse().unwrap()));
+    }
+
+    let first = DealGenerator::new(3).holds(1, ga).generate().unwrap();
+    assert_eq!(DealGenerator::new(3).holds(1, ga).generate().unwrap(), first);
+}
+
+#[test]
+fn impossible_constraints_are_reported() {
+    let ga: Card = "GA".parse().unwrap();
+    let both = DealGenerator::new(0).holds(0, ga).holds(1, ga).generate();
+    assert_eq!(both, Err(GenerateError::Contradiction));
+    let lacking = DealGenerator::new(0).holds(0, ga).lacks(0, ga).generate();
+    assert_eq!(lacking, Err(GenerateError::Contradiction));
+
+    let never = DealGenerator::new(0).filter(|_| false).attempts(5).generate();
+    assert_eq!(never, Err(GenerateError::NotFound { attempts: 5 }));
+}
+
+#[test]
+#[should_panic(expected = "seat 4 does not exist")]
+fn generator_rejects_seats_past_three() {
+    let _ = DealGenerator::new(0).min_trumps(4, &crate::gamemode::Gamemode::Solo(Suit::Herz), 5);
+}
+
+#[test]
+#[should_panic(expected = "seat 4 does not exist")]
+fn dealing_rejects_seats_past_three() {
+    Deck::new().deal(4, &Dealing::in_fours());
+}
+
+#[test]
+fn cutting_moves_the_top_under() {
+    let mut deck = Deck::new();
+    assert!(deck.cut(3));
+    let cards: Vec<Card> = deck.iter().copied().collect();
+    assert_eq!(format_cards(&cards[..4]), "S9 S8 S7 EA");
+    assert_eq!(deck.draw(), Some("SU".parse().unwrap()));
+    assert!(!deck.cut(40));
+
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..50 {
+        let at = Deck::new().cut_with(&mut rng);
+        assert!((MIN_CUT..=32 - MIN_CUT).contains(&at));
+    }
+}
+
+#[test]
+fn dealing_records_every_packet() {
+    let log = Deck::new().deal(1, &Dealing::three_two_three()).unwrap();
+    assert_eq!(log.cards.len(), 32);
+    assert!(log.hands.iter().all(|h| h.len() == 8));
+
+    // The seat after the dealer gets the first packet from the top
+    assert_eq!(format_cards(&log.hands[2][..3]), "S7 S8 S9");
+    let first = &log.cards[..3];
+    assert!(first.iter().all(|d| d.seat == 2 && d.packet == 0));
+    assert_eq!(log.cards[3], DealtCard { card: "SU".parse().unwrap(), seat: 3, packet: 1 });
+    assert_eq!(log.cards[31].seat, 1);
+    assert_eq!(log.cards[31].packet, 11);
+    for seat in 0..4 {
+        let dealt: Vec<Card> = log.cards.iter().filter(|d| d.seat == seat).map(|d| d.card).collect();
+        assert_eq!(dealt, log.hands[seat]);
+    }
+
+    // One card at a time from seat 0 is the plain four-by-eight deal
+    let single = Deck::shuffled(8).deal(3, &Dealing::new(vec![1; 8])).unwrap();
+    assert_eq!(single.hands, Deck::shuffled(8).deal_4x8().unwrap());
+    assert!(Deck::new().deal(0, &Dealing::new(vec![5, 4])).is_none());
+}
+
+#[test]
+fn decks_from_a_known_order() {
+    let shuffled = Deck::shuffled(11);
+    let order: Vec<Card> = shuffled.iter().copied().collect();
+    let mut deck = Deck::from_cards(order.clone()).unwrap();
+    assert_eq!(deck.draw(), order.last().copied());
+
+    let hands = Deck::shuffled(12).deal_4x8().unwrap();
+    assert_eq!(Deck::from_hands(&hands).unwrap().deal_4x8().unwrap(), hands);
+}
+
+#[test]
+fn invalid_decks_say_what_is_wrong() {
+    let mut cards: Vec<Card> = Deck::new().iter().copied().collect();
+    cards.pop();
+    assert_eq!(Deck::from_cards(cards.clone()).err(), Some(DeckError::Missing(parse_cards("S7").unwrap())));
+    cards.push(cards[0]);
+    assert_eq!(Deck::from_cards(cards).err(), Some(DeckError::Duplicate("EA".parse().unwrap())));
+
+    let mut hands = Deck::new().deal_4x8().unwrap();
+    let card = hands[1].pop().unwrap();
+    hands[2].push(card);
+    assert_eq!(Deck::from_hands(&hands).err(), Some(DeckError::HandSize { seat: 1, len: 7 }));
+    hands[2].pop();
+    assert_eq!(Deck::from_hands(&hands).err().unwrap().to_string(), format!("missing cards: {}", card.code()));
+}
+
+#[test]
+fn french_pattern_names_and_reads_cards() {
+    let french = Pattern::French;
+    let eo: Card = "EO".parse().unwrap();
+    assert_eq!(french.card_name(eo), "Kreuz Dame");
+    assert_eq!(Pattern::German.card_name(eo), "Eichel Ober");
+    assert_eq!(french.code(eo), "♣D");
+
+    let hand = parse_cards("EO GU HA S10 S7").unwrap();
+    assert_eq!(french.format_cards(&hand), "♣D ♠B ♥A ♦10 ♦7");
+    assert_eq!(french.parse_cards("♣D, ♠B ♥A ♦10 ♦7").unwrap(), hand);
+    assert_eq!(french.parse_card("Karo Bube"), "SU".parse());
+    assert_eq!(french.parse_card("treff dame"), Ok(eo));
+    assert_eq!(french.parse_card("Eichel Ober"), Ok(eo));
+    assert!(french.parse_card("♣X").is_err());
+}
+
+#[test]
+fn french_hands_alternate_colours() {
+    use crate::gamemode::Gamemode;
+
+    let mut hand = parse_cards("S7 GA EK HO HA GU E9 SA").unwrap();
+    Pattern::French.sort_hand(&Gamemode::Sauspiel(Suit::Eichel), &mut hand);
+    assert_eq!(format_cards(&hand), "HO GU HA EK E9 GA SA S7");
+    Pattern::German.sort_hand(&Gamemode::Sauspiel(Suit::Eichel), &mut hand);
+    assert_eq!(format_cards(&hand), "HO GU HA EK E9 GA SA S7");
+
+    let mut hand = parse_cards("S7 GA EK HO HA GU").unwrap();
+    Pattern::French.sort_hand(&Gamemode::Bettel, &mut hand);
+    assert_eq!(Pattern::French.format_cards(&hand), "♣K ♥A ♥D ♠A ♠B ♦7");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn cards_serialize_as_short_codes() {
+    let hand = parse_cards("EO H10 S7").unwrap();
+    let json = serde_json::to_string(&hand).unwrap();
+    assert_eq!(json, r#"["EO","H10","S7"]"#);
+    assert_eq!(serde_json::from_str::<Vec<Card>>(&json).unwrap(), hand);
+    assert_eq!(serde_json::to_string(&Suit::Gras).unwrap(), r#""G""#);
+    assert_eq!(serde_json::from_str::<Rank>(r#""U""#).unwrap(), Rank::Unter);
+
+    let set: CardSet = hand.iter().collect();
+    assert_eq!(serde_json::to_string(&set).unwrap(), json);
+    assert_eq!(serde_json::from_str::<CardSet>(&json).unwrap(), set);
+
+    let deck = Deck::shuffled(4);
+    let back: Deck = serde_json::from_str(&serde_json::to_string(&deck).unwrap()).unwrap();
+    assert!(back.iter().eq(deck.iter()));
+    assert!(serde_json::from_str::<Deck>(r#"["EO","EO"]"#).is_err());
+    assert!(serde_json::from_str::<Card>(r#""X9""#).is_err());
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::deck::{Card, CardSet, Deck, format_cards};
+use crate::game::Seat;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckError {
+    /// The card is given more than once.
+    Duplicate(Card),
+    /// These cards of the 32 are not given, in deck order.
+    Missing(Vec<Card>),
+    /// The hand of `seat` holds `len` cards instead of eight.
+    HandSize { seat: Seat, len: usize },
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckError::Duplicate(card) => write!(f, "{} is given twice", card.code()),
+            DeckError::Missing(cards) => write!(f, "missing cards: {}", format_cards(cards)),
+            DeckError::HandSize { seat, len } => write!(f, "seat {} has {} cards instead of 8", seat, len),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+impl Deck {
+    /// A deck in the given order, the way [`Deck::iter`] lists it: the last
+    /// card is on top and drawn first.
+    ///
+    /// Fails unless every card is given exactly once.
+    pub fn from_cards(cards: Vec<Card>) -> Result<Self, DeckError> {
+        check_complete(&cards)?;
+        Ok(Self { cards })
+    }
+
+    /// A deck that [`Deck::deal_4x8`] deals into `hands` again.
+    ///
+    /// Fails unless every card is given exactly once and each hand holds
+    /// eight of them.
+    pub fn from_hands(hands: &[Vec<Card>; 4]) -> Result<Self, DeckError> {
+        check_complete(hands.iter().flatten())?;
+        if let Some((seat, hand)) = hands.iter().enumerate().find(|(_, h)| h.len() != 8) {
+            return Err(DeckError::HandSize { seat, len: hand.len() });
+        }
+        let mut cards: Vec<Card> = (0..8).flat_map(|i| hands.iter().map(move |h| h[i])).collect();
+        cards.reverse();
+        Ok(Self { cards })
+    }
+}
+
+fn check_complete<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Result<(), DeckError> {
+    let mut seen = CardSet::EMPTY;
+    for &card in cards {
+        if !seen.insert(card) {
+            return Err(DeckError::Duplicate(card));
+        }
+    }
+    let missing = !seen;
+    if missing.is_empty() { Ok(()) } else { Err(DeckError::Missing(missing.to_vec())) }
+}
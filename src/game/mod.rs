@@ -0,0 +1,333 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::deck::Card;
+use crate::gamemode::{Announcement, GameRules};
+
+mod settlement;
+pub use settlement::{SettlementError, Tariff};
+
+mod view;
+pub use view::PlayerView;
+
+/// A seat at the table, `0..4` in playing order.
+pub type Seat = usize;
+
+/// Seat playing after `seat`.
+pub fn next_seat(seat: Seat) -> Seat {
+    (seat + 1) % 4
+}
+
+// Panics with a clear message instead of deep inside for seats past 3
+#[track_caller]
+pub(crate) fn assert_seat(seat: Seat) {
+    assert!(seat < 4, "seat {} does not exist, seats are 0..4", seat);
+}
+
+// Cards of the finished `tricks` as [`GameRules::legal_cards`] takes them.
+pub(crate) fn finished_cards(tricks: &[Trick]) -> Vec<Card> {
+    tricks.iter().flat_map(|t| t.cards.iter().copied()).collect()
+}
+
+/// Something a seat says aloud at the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Call {
+    /// Doubling the stakes after seeing the first cards.
+    Klopfen,
+    /// Passing in the auction ("weiter").
+    Weiter,
+    Announce(Announcement),
+    /// Doubling by the side playing against the declarer.
+    Kontra,
+    /// Redoubling by the declaring side after a Kontra.
+    Re,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    GameOver,
+    CardNotInHand(Card),
+    IllegalCard(Card),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::GameOver => write!(f, "the game is already over"),
+            GameError::CardNotInHand(card) => write!(f, "{} is not in the player's hand", card),
+            GameError::IllegalCard(card) => write!(f, "{} may not be played now", card),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trick {
+    leader: Seat,
+    cards: Vec<Card>,
+    winner: Option<Seat>,
+}
+
+impl Trick {
+    pub fn new(leader: Seat) -> Self {
+        Self { leader, cards: Vec::with_capacity(4), winner: None }
+    }
+
+    pub fn leader(&self) -> Seat {
+        self.leader
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Seat that played the `i`-th card of this trick.
+    pub fn seat_of(&self, i: usize) -> Seat {
+        (self.leader + i) % 4
+    }
+
+    /// Cards of this trick together with the seat that played them.
+    pub fn plays(&self) -> impl Iterator<Item = (Seat, Card)> + '_ {
+        self.cards.iter().enumerate().map(|(i, c)| (self.seat_of(i), *c))
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.cards.len() == 4
+    }
+
+    /// Seat that took the trick, once it is complete.
+    pub fn winner(&self) -> Option<Seat> {
+        self.winner
+    }
+}
+
+/// Result of a finished game from the declaring side's point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Outcome {
+    pub declarer_points: u8,
+    pub declarer_tricks: usize,
+    pub declarer_wins: bool,
+    /// The losing side took 30 points or less.
+    pub schneider: bool,
+    /// The losing side took no trick.
+    pub schwarz: bool,
+    /// Number of top trumps held by the declaring side without a gap.
+    pub laufende: usize,
+}
+
+/// The card play of a single game.
+///
+/// `Game` checks every card against its [`GameRules`], keeps the tricks and
+/// knows whose turn it is. Bidding happens before a `Game` is created.
+#[derive(Clone)]
+pub struct Game {
+    rules: Arc<dyn GameRules>,
+    initial_hands: [Vec<Card>; 4],
+    hands: [Vec<Card>; 4],
+    declarer: Option<Seat>,
+    partner: Option<Seat>,
+    calls: Vec<(Seat, Call)>,
+    tricks: Vec<Trick>,
+    current: Trick,
+}
+
+impl Game {
+    /// Starts a game with `leader` playing the first card.
+    ///
+    /// `declarer` is ignored for rules without one, such as Ramsch.
+    pub fn new(rules: impl GameRules + 'static, hands: [Vec<Card>; 4], leader: Seat, declarer: Option<Seat>) -> Self {
+        Self::with_rules(Arc::new(rules), hands, leader, declarer)
+    }
+
+    pub fn with_rules(rules: Arc<dyn GameRules>, hands: [Vec<Card>; 4], leader: Seat, declarer: Option<Seat>) -> Self {
+        let declarer = declarer.filter(|_| rules.has_declarer());
+        let partner = rules
+            .called_card()
+            .and_then(|card| hands.iter().position(|h| h.contains(&card)))
+            .filter(|&seat| declarer.is_some() && Some(seat) != declarer);
+        Self {
+            rules,
+            initial_hands: hands.clone(),
+            hands,
+            declarer,
+            partner,
+            calls: Vec::new(),
+            tricks: Vec::with_capacity(8),
+            current: Trick::new(leader),
+        }
+    }
+
+    pub fn rules(&self) -> &dyn GameRules {
+        self.rules.as_ref()
+    }
+
+    pub fn shared_rules(&self) -> Arc<dyn GameRules> {
+        Arc::clone(&self.rules)
+    }
+
+    pub fn declarer(&self) -> Option<Seat> {
+        self.declarer
+    }
+
+    /// The declarer's partner in a game with a called card.
+    ///
+    /// This is known to the engine from the start; players only learn it
+    /// once the called card has been played.
+    pub fn partner(&self) -> Option<Seat> {
+        self.partner
+    }
+
+    pub fn is_declarer_side(&self, seat: Seat) -> bool {
+        Some(seat) == self.declarer || Some(seat) == self.partner
+    }
+
+    /// Everything said at the table, in order.
+    pub fn calls(&self) -> &[(Seat, Call)] {
+        &self.calls
+    }
+
+    pub fn record_call(&mut self, seat: Seat, call: Call) {
+        self.calls.push((seat, call));
+    }
+
+    pub fn hand(&self, seat: Seat) -> &[Card] {
+        &self.hands[seat]
+    }
+
+    pub fn initial_hand(&self, seat: Seat) -> &[Card] {
+        &self.initial_hands[seat]
+    }
+
+    /// Tricks that have been completed so far.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// The trick being played; empty between tricks.
+    pub fn current_trick(&self) -> &Trick {
+        &self.current
+    }
+
+    /// Every card played so far, in playing order.
+    pub fn played_cards(&self) -> impl Iterator<Item = (Seat, Card)> + '_ {
+        self.tricks.iter().chain(std::iter::once(&self.current)).flat_map(|t| t.plays())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.hands.iter().all(|h| h.is_empty())
+    }
+
+    /// Seat to play the next card, `None` once the game is over.
+    pub fn current_player(&self) -> Option<Seat> {
+        if self.is_finished() {
+            None
+        } else {
+            Some(self.current.seat_of(self.current.cards.len()))
+        }
+    }
+
+    /// What `seat` is allowed to know about this game.
+    pub fn view(&self, seat: Seat) -> PlayerView {
+        PlayerView::new(self, seat)
+    }
+
+    /// Cards the current player may play.
+    pub fn legal_cards(&self) -> Vec<Card> {
+        match self.current_player() {
+            Some(seat) => self.rules.legal_cards(&self.hands[seat], &self.current.cards, &finished_cards(&self.tricks)),
+            None => Vec::new(),
+        }
+    }
+
+    /// Plays `card` for the current player.
+    ///
+    /// Returns the seat that took the trick if `card` completed it.
+    pub fn play(&mut self, card: Card) -> Result<Option<Seat>, GameError> {
+        let seat = self.current_player().ok_or(GameError::GameOver)?;
+        let hand = &self.hands[seat];
+        let pos = hand.iter().position(|c| *c == card).ok_or(GameError::CardNotInHand(card))?;
+        if !self.rules.legal_cards(hand, &self.current.cards, &finished_cards(&self.tricks)).contains(&card) {
+            return Err(GameError::IllegalCard(card));
+        }
+
+        self.hands[seat].remove(pos);
+        self.current.cards.push(card);
+        if !self.current.is_complete() {
+            return Ok(None);
+        }
+
+        let winner = self.current.seat_of(self.rules.winning_index(&self.current.cards));
+        self.current.winner = Some(winner);
+        let trick = std::mem::replace(&mut self.current, Trick::new(winner));
+        self.tricks.push(trick);
+        Ok(Some(winner))
+    }
+
+    /// Points taken by `seat` so far.
+    pub fn points(&self, seat: Seat) -> u8 {
+        self.tricks
+            .iter()
+            .filter(|t| t.winner == Some(seat))
+            .flat_map(|t| t.cards.iter())
+            .map(|c| self.rules.card_points(c))
+            .sum()
+    }
+
+    /// Number of tricks taken by `seat` so far.
+    pub fn tricks_won(&self, seat: Seat) -> usize {
+        self.tricks.iter().filter(|t| t.winner == Some(seat)).count()
+    }
+
+    /// Top trumps held by the declaring side before the first card, counted
+    /// until the first trump the other side holds.
+    ///
+    /// Counts from the other side's view if they hold the highest trump.
+    pub fn laufende(&self) -> usize {
+        let held_by_declarer = |card: &Card| {
+            (0..4).any(|seat| self.is_declarer_side(seat) && self.initial_hands[seat].contains(card))
+        };
+        let trumps = self.rules.trump_order();
+        let Some(first) = trumps.first() else {
+            return 0;
+        };
+        let side = held_by_declarer(first);
+        trumps.iter().take_while(|c| held_by_declarer(c) == side).count()
+    }
+
+    /// Result of the game once every card has been played.
+    ///
+    /// `None` while the game is running or if it has no declarer.
+    pub fn outcome(&self) -> Option<Outcome> {
+        if !self.is_finished() {
+            return None;
+        }
+        self.declarer?;
+
+        let declarer_side = (0..4).filter(|&seat| self.is_declarer_side(seat));
+        let declarer_points = declarer_side.clone().map(|seat| self.points(seat)).sum();
+        let declarer_tricks = declarer_side.map(|seat| self.tricks_won(seat)).sum();
+        let declarer_wins = self.rules.declarer_wins(declarer_points, declarer_tricks);
+
+        let (loser_points, loser_tricks) = if declarer_wins {
+            (120 - declarer_points, 8 - declarer_tricks)
+        } else {
+            (declarer_points, declarer_tricks)
+        };
+
+        Some(Outcome {
+            declarer_points,
+            declarer_tricks,
+            declarer_wins,
+            schneider: loser_points <= 30,
+            schwarz: loser_tricks == 0,
+            laufende: self.laufende(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
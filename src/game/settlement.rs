@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::game::{Call, Game, Seat};
+use crate::gamemode::{GameRules, TariffClass};
+
+/// Prices a table plays for, in whatever unit it counts (usually cents).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tariff {
+    /// Sauspiel and Ramsch.
+    pub normal: i64,
+    /// Every game played alone.
+    pub solo: i64,
+    /// Added for Schneider, for Schwarz and for each paid Laufender.
+    pub extra: i64,
+}
+
+impl Default for Tariff {
+    fn default() -> Self {
+        Self { normal: 10, solo: 50, extra: 10 }
+    }
+}
+
+impl Tariff {
+    pub fn base(&self, class: TariffClass) -> i64 {
+        match class {
+            TariffClass::Normal => self.normal,
+            TariffClass::Solo => self.solo,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettlementError {
+    /// The game is still running.
+    Running,
+    /// An amount does not fit in an `i64`, e.g. after too many doublings.
+    Overflow,
+}
+
+impl fmt::Display for SettlementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettlementError::Running => write!(f, "the game is still running"),
+            SettlementError::Overflow => write!(f, "the settlement does not fit in an i64"),
+        }
+    }
+}
+
+impl std::error::Error for SettlementError {}
+
+impl Game {
+    /// What each seat wins (positive) or pays (negative) for the finished
+    /// game; the four amounts add up to zero.
+    ///
+    /// Every Klopfen, Kontra and Re doubles the price. In a Sauspiel each
+    /// seat wins or pays the price once, in a solo game the declarer settles
+    /// with each of the other three. In Ramsch the seat with the most points
+    /// pays every other seat, unless one seat took every trick
+    /// (Durchmarsch) and is paid by all.
+    pub fn settlement(&self, tariff: &Tariff) -> Result<[i64; 4], SettlementError> {
+        if !self.is_finished() {
+            return Err(SettlementError::Running);
+        }
+        let rules = self.rules();
+        let doublings = self
+            .calls()
+            .iter()
+            .filter(|(_, call)| matches!(call, Call::Klopfen | Call::Kontra | Call::Re))
+            .count();
+        let factor = u32::try_from(doublings)
+            .ok()
+            .and_then(|d| 2i64.checked_pow(d))
+            .and_then(|f| f.checked_mul(rules.multiplier()))
+            .ok_or(SettlementError::Overflow)?;
+        let base = tariff.base(rules.tariff_class());
+
+        let Some(outcome) = self.outcome() else {
+            let price = base.checked_mul(factor).ok_or(SettlementError::Overflow)?;
+            return settle_ramsch(self, price).ok_or(SettlementError::Overflow);
+        };
+
+        let mut extras = 0;
+        if plays_for_points(rules) {
+            extras += outcome.schneider as i64 + outcome.schwarz as i64;
+        }
+        if rules.laufende_from().is_some_and(|from| outcome.laufende >= from) {
+            extras += outcome.laufende as i64;
+        }
+        let won = extras
+            .checked_mul(tariff.extra)
+            .and_then(|e| e.checked_add(base))
+            .and_then(|p| p.checked_mul(factor))
+            .and_then(|p| if outcome.declarer_wins { Some(p) } else { p.checked_neg() })
+            .ok_or(SettlementError::Overflow)?;
+
+        let side: Vec<Seat> = (0..4).filter(|&s| self.is_declarer_side(s)).collect();
+        let opponents = (4 - side.len()) as i64 / side.len() as i64;
+        let (side_gets, others_get) = won
+            .checked_mul(opponents)
+            .zip(won.checked_neg())
+            .ok_or(SettlementError::Overflow)?;
+        Ok(std::array::from_fn(|s| if side.contains(&s) { side_gets } else { others_get }))
+    }
+}
+
+// Bettel, Tout and Sie are decided by tricks and pay no Schneider or Schwarz
+fn plays_for_points(rules: &dyn GameRules) -> bool {
+    !rules.declarer_wins(0, 0) && rules.declarer_wins(120, 7)
+}
+
+// `None` if an amount overflows
+fn settle_ramsch(game: &Game, price: i64) -> Option<[i64; 4]> {
+    let paid = price.checked_neg()?;
+    if let Some(seat) = (0..4).find(|&s| game.tricks_won(s) == game.tricks().len()) {
+        let won = price.checked_mul(3)?;
+        return Some(std::array::from_fn(|s| if s == seat { won } else { paid }));
+    }
+    let most = (0..4).map(|s| game.points(s)).max().unwrap_or(0);
+    let losers = (0..4).filter(|&s| game.points(s) == most).count() as i64;
+    let (lost, won) = paid.checked_mul(4 - losers).zip(price.checked_mul(losers))?;
+    Some(std::array::from_fn(|s| if game.points(s) == most { lost } else { won }))
+}
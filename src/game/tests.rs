@@ -0,0 +1,307 @@
+use std::fmt;
+
+use super::*;
+use crate::deck::{Deck, Rank, Suit};
+use crate::gamemode::{Announcement, Gamemode};
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+fn dealt_hands() -> [Vec<Card>; 4] {
+    Deck::new().deal_4x8().expect("deck should contain 32 cards")
+}
+
+fn play_out(game: &mut Game) {
+    while let Some(&card) = game.legal_cards().first() {
+        game.play(card).expect("legal card should be accepted");
+    }
+}
+
+#[test]
+fn plays_a_full_game() {
+    let mut game = Game::new(Gamemode::Solo(Suit::Gras), dealt_hands(), 0, Some(1));
+    assert_eq!(game.current_player(), Some(0));
+
+    play_out(&mut game);
+
+    assert!(game.is_finished());
+    assert_eq!(game.current_player(), None);
+    assert_eq!(game.tricks().len(), 8);
+    assert_eq!((0..4).map(|s| game.points(s) as u32).sum::<u32>(), 120);
+    assert_eq!((0..4).map(|s| game.tricks_won(s)).sum::<usize>(), 8);
+
+    let outcome = game.outcome().expect("solo has a declarer");
+    assert_eq!(outcome.declarer_points, game.points(1));
+    assert_eq!(outcome.declarer_wins, game.points(1) > 60);
+    assert_eq!(game.play(game.initial_hand(0)[0]), Err(GameError::GameOver));
+}
+
+#[test]
+fn trick_winner_leads_next() {
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Neun), card(Suit::Gras, Rank::Neun)],
+        vec![card(Suit::Eichel, Rank::Ass), card(Suit::Gras, Rank::Acht)],
+        vec![card(Suit::Eichel, Rank::Sieben), card(Suit::Gras, Rank::Sieben)],
+        vec![card(Suit::Eichel, Rank::Acht), card(Suit::Gras, Rank::Ass)],
+    ];
+    let mut game = Game::new(Gamemode::Sauspiel(Suit::Schell), hands, 2, Some(0));
+
+    assert_eq!(game.play(card(Suit::Eichel, Rank::Sieben)), Ok(None));
+    assert_eq!(game.play(card(Suit::Eichel, Rank::Acht)), Ok(None));
+    assert_eq!(game.play(card(Suit::Eichel, Rank::Neun)), Ok(None));
+    assert_eq!(game.play(card(Suit::Eichel, Rank::Ass)), Ok(Some(1)));
+
+    assert_eq!(game.current_player(), Some(1));
+    assert_eq!(game.tricks()[0].winner(), Some(1));
+    assert_eq!(game.tricks()[0].plays().next(), Some((2, card(Suit::Eichel, Rank::Sieben))));
+    assert_eq!(game.points(1), 11);
+}
+
+#[test]
+fn bettel_herz_ober_does_not_take_an_eichel_trick() {
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Neun), card(Suit::Gras, Rank::Neun)],
+        vec![card(Suit::Herz, Rank::Ober), card(Suit::Schell, Rank::Sieben)],
+        vec![card(Suit::Eichel, Rank::Sieben), card(Suit::Gras, Rank::Sieben)],
+        vec![card(Suit::Schell, Rank::Unter), card(Suit::Gras, Rank::Ass)],
+    ];
+    let mut game = Game::new(Gamemode::Bettel, hands, 0, Some(0));
+
+    game.play(card(Suit::Eichel, Rank::Neun)).unwrap();
+    // Without trumps the Herz Ober does not follow Eichel, so anything goes
+    assert_eq!(game.legal_cards().len(), 2);
+    game.play(card(Suit::Herz, Rank::Ober)).unwrap();
+    game.play(card(Suit::Eichel, Rank::Sieben)).unwrap();
+    assert_eq!(game.play(card(Suit::Schell, Rank::Unter)), Ok(Some(0)));
+}
+
+#[test]
+fn rejects_cards_not_in_hand_or_illegal() {
+    let mut game = Game::new(Gamemode::Wenz(None), dealt_hands(), 0, Some(0));
+    let hand = game.hand(0).to_vec();
+    let foreign = game.hand(1)[0];
+
+    assert_eq!(game.play(foreign), Err(GameError::CardNotInHand(foreign)));
+    game.play(hand[0]).unwrap();
+
+    let legal = game.legal_cards();
+    let illegal = game.hand(1).iter().copied().find(|c| !legal.contains(c));
+    if let Some(illegal) = illegal {
+        assert_eq!(game.play(illegal), Err(GameError::IllegalCard(illegal)));
+    }
+    assert_eq!(game.hand(1).len(), 8);
+}
+
+#[test]
+fn sauspiel_partner_holds_called_sau() {
+    let hands = dealt_hands();
+    let sau = card(Suit::Gras, Rank::Ass);
+    let holder = hands.iter().position(|h| h.contains(&sau)).unwrap();
+    let declarer = (holder + 1) % 4;
+
+    let game = Game::new(Gamemode::Sauspiel(Suit::Gras), hands, 0, Some(declarer));
+    assert_eq!(game.partner(), Some(holder));
+    assert!(game.is_declarer_side(holder));
+    assert!(!game.is_declarer_side((holder + 2) % 4));
+
+    let ramsch = Game::new(Gamemode::Ramsch, dealt_hands(), 0, Some(0));
+    assert_eq!(ramsch.declarer(), None);
+    assert_eq!(ramsch.partner(), None);
+}
+
+#[test]
+fn counts_laufende_with_and_without() {
+    let obers: Vec<Card> = [Suit::Eichel, Suit::Gras, Suit::Herz]
+        .iter()
+        .map(|&s| card(s, Rank::Ober))
+        .collect();
+    let hands = [obers, vec![card(Suit::Schell, Rank::Ober)], vec![], vec![]];
+
+    let with = Game::new(Gamemode::Solo(Suit::Eichel), hands.clone(), 0, Some(0));
+    assert_eq!(with.laufende(), 3);
+
+    let without = Game::new(Gamemode::Solo(Suit::Eichel), hands, 0, Some(1));
+    assert_eq!(without.laufende(), 3);
+}
+
+// A house variant where the Unter outrank the Ober
+struct UnterSolo(Suit);
+
+impl fmt::Display for UnterSolo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-Untersolo", self.0)
+    }
+}
+
+impl GameRules for UnterSolo {
+    fn is_trump(&self, card: &Card) -> bool {
+        matches!(card.rank, Rank::Ober | Rank::Unter) || card.suit == self.0
+    }
+
+    fn card_strength(&self, card: &Card, led: &Card) -> u16 {
+        match card.rank {
+            Rank::Unter => 300 + 4 - card.suit as u16,
+            Rank::Ober => 200 + 4 - card.suit as u16,
+            _ if card.suit == self.0 => 100 + 8 - card.rank as u16,
+            _ if !self.is_trump(led) && card.suit == led.suit => 8 - card.rank as u16,
+            _ => 0,
+        }
+    }
+}
+
+#[test]
+fn plays_custom_rules() {
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Ober)],
+        vec![card(Suit::Schell, Rank::Unter)],
+        vec![card(Suit::Herz, Rank::Ass)],
+        vec![card(Suit::Herz, Rank::Sieben)],
+    ];
+    let mut game = Game::new(UnterSolo(Suit::Herz), hands, 0, Some(0));
+    play_out(&mut game);
+
+    assert_eq!(game.tricks()[0].winner(), Some(1));
+    assert_eq!(game.rules().to_string(), "Herz-Untersolo");
+    assert_eq!(game.rules().trump_order()[0], card(Suit::Eichel, Rank::Unter));
+}
+
+#[test]
+fn view_shows_only_own_hand() {
+    let mut game = Game::new(Gamemode::Solo(Suit::Eichel), dealt_hands(), 0, Some(2));
+    game.record_call(2, Call::Announce(Announcement::Game(Gamemode::Solo(Suit::Eichel))));
+    let first = game.legal_cards()[0];
+    game.play(first).unwrap();
+
+    let view = game.view(1);
+    assert_eq!(view.seat(), 1);
+    assert_eq!(view.hand(), game.hand(1));
+    assert_eq!(view.declarer(), Some(2));
+    assert_eq!(view.calls().len(), 1);
+    assert_eq!(view.current_trick().cards(), &[first]);
+    assert_eq!(view.hand_size(0), 7);
+    assert_eq!(view.hand_size(1), 8);
+    assert!(view.is_my_turn());
+    assert_eq!(view.legal_cards(), game.legal_cards());
+
+    let unseen = view.unseen_cards();
+    assert_eq!(unseen.len(), 23);
+    assert!(game.hand(0).iter().all(|c| unseen.contains(c)));
+    assert!(!unseen.contains(&first));
+
+    assert!(game.view(2).legal_cards().is_empty());
+}
+
+#[test]
+fn view_reveals_partner_once_sau_is_played() {
+    let sau = card(Suit::Eichel, Rank::Ass);
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Neun), card(Suit::Herz, Rank::Acht)],
+        vec![sau, card(Suit::Gras, Rank::Acht)],
+        vec![card(Suit::Eichel, Rank::Sieben), card(Suit::Gras, Rank::Sieben)],
+        vec![card(Suit::Eichel, Rank::Acht), card(Suit::Gras, Rank::Ass)],
+    ];
+    let mut game = Game::new(Gamemode::Sauspiel(Suit::Eichel), hands, 0, Some(0));
+
+    assert_eq!(game.view(0).partner(), None);
+    assert_eq!(game.view(1).partner(), Some(1));
+    assert_eq!(game.view(3).partner(), None);
+
+    game.play(card(Suit::Eichel, Rank::Neun)).unwrap();
+    game.play(sau).unwrap();
+
+    assert_eq!(game.view(0).partner(), Some(1));
+    assert!(game.view(3).is_known_declarer_side(1));
+}
+
+#[test]
+fn view_before_game_has_no_rules() {
+    let hands = dealt_hands();
+    let view = PlayerView::before_game(3, &hands[3], 0, &[(0, Call::Weiter)]);
+
+    assert!(view.rules().is_none());
+    assert_eq!(view.current_player(), None);
+    assert!(view.legal_cards().is_empty());
+    assert_eq!(view.calls(), &[(0, Call::Weiter)]);
+    assert_eq!(view.unseen_cards().len(), 24);
+}
+
+fn ober_unter_solo(rules: impl GameRules + 'static) -> Game {
+    let (top, rest): (Vec<Card>, Vec<Card>) =
+        Deck::new().iter().partition(|c| matches!(c.rank, Rank::Ober | Rank::Unter));
+    let hands = [top, rest[..8].to_vec(), rest[8..16].to_vec(), rest[16..].to_vec()];
+    Game::new(rules, hands, 0, Some(0))
+}
+
+#[test]
+fn settles_a_solo_schwarz_with_laufende() {
+    let mut game = ober_unter_solo(Gamemode::Solo(Suit::Herz));
+    assert_eq!(game.settlement(&Tariff::default()), Err(SettlementError::Running));
+    play_out(&mut game);
+
+    // 50 + Schneider + Schwarz + 8 Laufende
+    assert_eq!(game.settlement(&Tariff::default()), Ok([450, -150, -150, -150]));
+
+    let mut doubled = ober_unter_solo(Gamemode::Solo(Suit::Herz));
+    doubled.record_call(1, Call::Kontra);
+    play_out(&mut doubled);
+    assert_eq!(doubled.settlement(&Tariff::default()), Ok([900, -300, -300, -300]));
+}
+
+#[test]
+fn tout_pays_double_without_schneider() {
+    let mut game = ober_unter_solo(Announcement::Tout(Gamemode::Solo(Suit::Herz)));
+    play_out(&mut game);
+    assert_eq!(game.settlement(&Tariff::default()), Ok([780, -260, -260, -260]));
+}
+
+#[test]
+fn too_many_doublings_are_an_error() {
+    let mut game = ober_unter_solo(Gamemode::Solo(Suit::Herz));
+    for _ in 0..64 {
+        game.record_call(1, Call::Kontra);
+    }
+    play_out(&mut game);
+    assert_eq!(game.settlement(&Tariff::default()), Err(SettlementError::Overflow));
+}
+
+#[test]
+fn settlements_add_up_to_zero() {
+    for rules in [Gamemode::Sauspiel(Suit::Gras), Gamemode::Wenz(None), Gamemode::Bettel, Gamemode::Ramsch] {
+        let declarer = rules.has_declarer().then_some(2);
+        let mut game = Game::new(rules, dealt_hands(), 0, declarer);
+        play_out(&mut game);
+        let settlement = game.settlement(&Tariff::default()).unwrap();
+        assert_eq!(settlement.iter().sum::<i64>(), 0, "{}", rules);
+
+        if let Some(partner) = game.partner() {
+            assert_eq!(settlement[2], settlement[partner]);
+        }
+        if rules == Gamemode::Ramsch {
+            let most = (0..4).max_by_key(|&s| game.points(s)).unwrap();
+            assert!(settlement[most] < 0);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn calls_and_tricks_serialize() {
+    let calls = vec![
+        Call::Klopfen,
+        Call::Announce(Announcement::Game(Gamemode::Sauspiel(Suit::Eichel))),
+        Call::Announce(Announcement::Tout(Gamemode::Wenz(None))),
+        Call::Kontra,
+    ];
+    let json = serde_json::to_string(&calls).unwrap();
+    assert_eq!(
+        json,
+        r#"["Klopfen",{"Announce":{"Game":{"Sauspiel":"E"}}},{"Announce":{"Tout":{"Wenz":null}}},"Kontra"]"#
+    );
+    assert_eq!(serde_json::from_str::<Vec<Call>>(&json).unwrap(), calls);
+
+    let mut game = Game::new(Gamemode::Solo(Suit::Herz), dealt_hands(), 0, Some(0));
+    play_out(&mut game);
+    let tricks: Vec<Trick> = serde_json::from_str(&serde_json::to_string(game.tricks()).unwrap()).unwrap();
+    assert_eq!(tricks, game.tricks());
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::deck::{Card, Deck};
+use crate::game::{Call, Game, Seat, Trick, finished_cards};
+use crate::gamemode::GameRules;
+
+/// Everything one seat is allowed to know at a given moment.
+///
+/// A view is a snapshot: it owns its data and does not change when the game
+/// goes on. It never contains other seats' hands, and the partner of a
+/// Sauspiel only shows up once the called Sau has been played (or if the
+/// viewing seat holds it).
+#[derive(Clone)]
+pub struct PlayerView {
+    seat: Seat,
+    hand: Vec<Card>,
+    rules: Option<Arc<dyn GameRules>>,
+    declarer: Option<Seat>,
+    partner: Option<Seat>,
+    calls: Vec<(Seat, Call)>,
+    tricks: Vec<Trick>,
+    current: Trick,
+    hand_sizes: [usize; 4],
+}
+
+impl PlayerView {
+    pub(crate) fn new(game: &Game, seat: Seat) -> Self {
+        let called_card = game.rules().called_card();
+        let partner_known = called_card.is_some_and(|card| {
+            game.hand(seat).contains(&card) || game.played_cards().any(|(_, c)| c == card)
+        });
+
+        Self {
+            seat,
+            hand: game.hand(seat).to_vec(),
+            rules: Some(game.shared_rules()),
+            declarer: game.declarer(),
+            partner: game.partner().filter(|_| partner_known),
+            calls: game.calls().to_vec(),
+            tricks: game.tricks().to_vec(),
+            current: game.current_trick().clone(),
+            hand_sizes: std::array::from_fn(|s| game.hand(s).len()),
+        }
+    }
+
+    /// View of `seat` during bidding, before any game has been chosen.
+    ///
+    /// `leader` is the seat that will play the first card.
+    pub fn before_game(seat: Seat, hand: &[Card], leader: Seat, calls: &[(Seat, Call)]) -> Self {
+        Self {
+            seat,
+            hand: hand.to_vec(),
+            rules: None,
+            declarer: None,
+            partner: None,
+            calls: calls.to_vec(),
+            tricks: Vec::new(),
+            current: Trick::new(leader),
+            hand_sizes: [hand.len(); 4],
+        }
+    }
+
+    pub fn seat(&self) -> Seat {
+        self.seat
+    }
+
+    pub fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    /// Rules of the game being played, `None` during bidding.
+    pub fn rules(&self) -> Option<&dyn GameRules> {
+        self.rules.as_deref()
+    }
+
+    pub fn shared_rules(&self) -> Option<Arc<dyn GameRules>> {
+        self.rules.clone()
+    }
+
+    pub fn declarer(&self) -> Option<Seat> {
+        self.declarer
+    }
+
+    /// The declarer's partner, once it has been revealed to this seat.
+    pub fn partner(&self) -> Option<Seat> {
+        self.partner
+    }
+
+    /// Whether `seat` is known to play with the declarer.
+    pub fn is_known_declarer_side(&self, seat: Seat) -> bool {
+        Some(seat) == self.declarer || Some(seat) == self.partner
+    }
+
+    pub fn calls(&self) -> &[(Seat, Call)] {
+        &self.calls
+    }
+
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    pub fn current_trick(&self) -> &Trick {
+        &self.current
+    }
+
+    /// Every card played so far, in playing order.
+    pub fn played_cards(&self) -> impl Iterator<Item = (Seat, Card)> + '_ {
+        self.tricks.iter().chain(std::iter::once(&self.current)).flat_map(|t| t.plays())
+    }
+
+    /// Cards neither in this seat's hand nor played yet.
+    pub fn unseen_cards(&self) -> Vec<Card> {
+        let played: Vec<Card> = self.played_cards().map(|(_, c)| c).collect();
+        Deck::new()
+            .iter()
+            .copied()
+            .filter(|c| !self.hand.contains(c) && !played.contains(c))
+            .collect()
+    }
+
+    /// Number of cards `seat` still holds.
+    pub fn hand_size(&self, seat: Seat) -> usize {
+        self.hand_sizes[seat]
+    }
+
+    /// Seat to play the next card, `None` during bidding or once the game is over.
+    pub fn current_player(&self) -> Option<Seat> {
+        if self.rules.is_none() || self.hand_sizes.iter().all(|&n| n == 0) {
+            None
+        } else {
+            Some(self.current.seat_of(self.current.cards().len()))
+        }
+    }
+
+    pub fn is_my_turn(&self) -> bool {
+        self.current_player() == Some(self.seat)
+    }
+
+    /// Cards this seat may play, empty unless it is this seat's turn.
+    pub fn legal_cards(&self) -> Vec<Card> {
+        match self.rules() {
+            Some(rules) if self.is_my_turn() => {
+                rules.legal_cards(&self.hand, self.current.cards(), &finished_cards(&self.tricks))
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Points `seat` has taken so far.
+    pub fn points(&self, seat: Seat) -> u8 {
+        let Some(rules) = self.rules() else {
+            return 0;
+        };
+        self.tricks
+            .iter()
+            .filter(|t| t.winner() == Some(seat))
+            .flat_map(|t| t.cards().iter())
+            .map(|c| rules.card_points(c))
+            .sum()
+    }
+}
@@ -0,0 +1,138 @@
+use strum::IntoEnumIterator;
+
+use crate::deck::{Card, Rank, Suit};
+use crate::gamemode::Gamemode;
+
+/// House rules deciding which games may be announced at the table.
+///
+/// Sauspiel and Solo are always playable; everything else can be switched
+/// on or off per table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ruleset {
+    pub wenz: bool,
+    pub farbwenz: bool,
+    pub geier: bool,
+    pub farbgeier: bool,
+    pub bettel: bool,
+    pub tout: bool,
+    pub sie: bool,
+    /// Play a Ramsch when nobody announces a game instead of throwing the
+    /// cards in.
+    pub ramsch: bool,
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self {
+            wenz: true,
+            farbwenz: false,
+            geier: false,
+            farbgeier: false,
+            bettel: false,
+            tout: true,
+            sie: true,
+            ramsch: false,
+        }
+    }
+}
+
+impl Ruleset {
+    /// A table that allows every game this crate knows about.
+    pub fn all() -> Self {
+        Self {
+            wenz: true,
+            farbwenz: true,
+            geier: true,
+            farbgeier: true,
+            bettel: true,
+            tout: true,
+            sie: true,
+            ramsch: true,
+        }
+    }
+}
+
+/// A game a player may announce during bidding.
+///
+/// `Tout` and `Sie` wrap the solo game they are played on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Announcement {
+    Game(Gamemode),
+    Tout(Gamemode),
+    Sie(Gamemode),
+}
+
+impl Announcement {
+    pub fn gamemode(&self) -> &Gamemode {
+        match self {
+            Announcement::Game(mode) | Announcement::Tout(mode) | Announcement::Sie(mode) => mode,
+        }
+    }
+
+    /// Bidding precedence: any Tout outbids every plain game and any Sie
+    /// outbids every Tout.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Announcement::Game(mode) => mode.precedence(),
+            Announcement::Tout(mode) => 10 + mode.precedence(),
+            Announcement::Sie(mode) => 20 + mode.precedence(),
+        }
+    }
+}
+
+/// Lists every announcement `hand` is allowed to make under `rules`.
+///
+/// Sauspiele are only offered for suits the hand can call: not Herz, no own
+/// Sau of that suit, and at least one card of that suit which is not a trump.
+/// `Sie` requires the hand to hold exactly the eight highest trumps.
+pub fn available_announcements(hand: &[Card], rules: &Ruleset) -> Vec<Announcement> {
+    let mut announcements = Vec::new();
+
+    for suit in [Suit::Eichel, Suit::Gras, Suit::Schell] {
+        let mode = Gamemode::Sauspiel(suit);
+        let holds_sau = hand.contains(&Card { suit, rank: Rank::Ass });
+        let holds_suit = hand.iter().any(|c| c.suit == suit && !mode.is_trump(c));
+        if !holds_sau && holds_suit {
+            announcements.push(Announcement::Game(mode));
+        }
+    }
+
+    if rules.bettel {
+        announcements.push(Announcement::Game(Gamemode::Bettel));
+    }
+
+    let mut solos = Vec::new();
+    if rules.farbgeier {
+        solos.extend(Suit::iter().map(|s| Gamemode::Geier(Some(s))));
+    }
+    if rules.geier {
+        solos.push(Gamemode::Geier(None));
+    }
+    if rules.farbwenz {
+        solos.extend(Suit::iter().map(|s| Gamemode::Wenz(Some(s))));
+    }
+    if rules.wenz {
+        solos.push(Gamemode::Wenz(None));
+    }
+    solos.extend(Suit::iter().map(Gamemode::Solo));
+
+    for mode in solos {
+        let sie = rules.sie && is_sie(&mode, hand);
+        announcements.push(Announcement::Game(mode));
+        if rules.tout {
+            announcements.push(Announcement::Tout(mode));
+        }
+        if sie {
+            announcements.push(Announcement::Sie(mode));
+        }
+    }
+
+    announcements
+}
+
+fn is_sie(mode: &Gamemode, hand: &[Card]) -> bool {
+    let trumps = mode.trumps();
+    hand.len() == 8 && trumps.len() >= 8 && trumps[..8].iter().all(|c| hand.contains(c))
+}
@@ -1,5 +1,19 @@
-use crate::deck::{Card, Suit, Rank};
+use std::cmp::Reverse;
 
+use crate::deck::{Card, CardSet, Deck, Suit, Rank};
+
+mod announcement;
+pub use announcement::{Announcement, Ruleset, available_announcements};
+
+mod names;
+pub use names::ParseGamemodeError;
+pub(crate) use names::sau_name;
+
+mod rules;
+pub use rules::{GameRules, follow_suit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Gamemode {
     Sauspiel(Suit),
     Solo(Suit),
@@ -9,16 +23,139 @@ pub enum Gamemode {
     Ramsch
 }
 
+/// Who plays together in a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Parties {
+    /// Declarer and the holder of the called Sau against the other two.
+    Partnership,
+    /// Declarer alone against the other three.
+    Solo,
+    /// Everyone plays for themselves (Ramsch).
+    FreeForAll,
+}
+
+/// The base tariff a game is paid with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TariffClass {
+    Normal,
+    Solo,
+}
+
 impl Gamemode {
+    pub fn parties(&self) -> Parties {
+        match self {
+            Gamemode::Sauspiel(_) => Parties::Partnership,
+            Gamemode::Ramsch => Parties::FreeForAll,
+            _ => Parties::Solo,
+        }
+    }
+
+    pub fn is_solo(&self) -> bool {
+        self.parties() == Parties::Solo
+    }
+
+    /// Sauspiel and Ramsch are paid with the normal tariff, every game a
+    /// declarer plays alone (Bettel included) with the solo tariff.
+    pub fn tariff_class(&self) -> TariffClass {
+        if self.is_solo() { TariffClass::Solo } else { TariffClass::Normal }
+    }
+
+    /// Bidding precedence, higher outbids lower:
+    /// Sauspiel < Bettel < Farbwenz/Farbgeier < Wenz/Geier < Solo.
+    ///
+    /// Ramsch is never announced and has the lowest value.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Gamemode::Ramsch => 0,
+            Gamemode::Sauspiel(_) => 1,
+            Gamemode::Bettel => 2,
+            Gamemode::Wenz(Some(_)) | Gamemode::Geier(Some(_)) => 3,
+            Gamemode::Wenz(None) | Gamemode::Geier(None) => 4,
+            Gamemode::Solo(_) => 5,
+        }
+    }
+
     pub fn winning_card<'a>(&self, cards: [&'a Card; 4]) -> &'a Card  {
         match self {
-            Gamemode::Sauspiel(_) | Gamemode::Ramsch | Gamemode::Bettel =>
+            Gamemode::Sauspiel(_) | Gamemode::Ramsch =>
                 winner_for_trump(Suit::Herz, cards),
+            Gamemode::Bettel => winner_of_led_suit(cards, bettel_strength),
             Gamemode::Solo(solo_suit) => winner_for_trump(*solo_suit, cards),
             Gamemode::Wenz(wenz_suit) => winner_for_wenz(Rank::Unter, *wenz_suit, cards),
             Gamemode::Geier(geier_suit) => winner_for_wenz(Rank::Ober, *geier_suit, cards),
         }
     }
+
+    /// Whether `card` is a trump; a Bettel is played without any.
+    pub fn is_trump(&self, card: &Card) -> bool {
+        match self {
+            Gamemode::Bettel => false,
+            Gamemode::Sauspiel(_) | Gamemode::Ramsch =>
+                is_trump(card, &[Rank::Ober, Rank::Unter], Some(Suit::Herz)),
+            Gamemode::Solo(solo_suit) => is_trump(card, &[Rank::Ober, Rank::Unter], Some(*solo_suit)),
+            Gamemode::Wenz(wenz_suit) => is_trump(card, &[Rank::Unter], *wenz_suit),
+            Gamemode::Geier(geier_suit) => is_trump(card, &[Rank::Ober], *geier_suit),
+        }
+    }
+
+    /// All trumps of this game mode, strongest first.
+    pub fn trumps(&self) -> Vec<Card> {
+        let mut trumps: Vec<Card> = Deck::new().iter().copied().filter(|c| self.is_trump(c)).collect();
+        trumps.sort_by_key(|c| Reverse(self.trump_rank(c)));
+        trumps
+    }
+
+    /// The trumps of this game mode as a set.
+    pub fn trump_set(&self) -> CardSet {
+        self.trumps().into_iter().collect()
+    }
+
+    /// Cards of `suit` that are not trumps in this game mode.
+    pub fn suit_set(&self, suit: Suit) -> CardSet {
+        CardSet::suit(suit) - self.trump_set()
+    }
+
+    /// Sorts `hand` for display: trumps first from strongest to weakest, then
+    /// each suit (Eichel, Gras, Herz, Schell) from Ass down to Sieben.
+    pub fn sort_hand(&self, hand: &mut [Card]) {
+        hand.sort_by_key(|c| self.sort_key(c));
+    }
+
+    /// Sorts `hand` before a game has been announced, using the Herz trumps
+    /// of a Sauspiel.
+    pub fn sort_neutral(hand: &mut [Card]) {
+        Gamemode::Sauspiel(Suit::Herz).sort_hand(hand);
+    }
+
+    fn sort_key(&self, card: &Card) -> (u8, Reverse<u16>) {
+        if self.is_trump(card) {
+            (0, Reverse(self.trump_rank(card)))
+        } else {
+            (1 + card.suit as u8, Reverse(self.plain_strength(card.rank) as u16))
+        }
+    }
+
+    // Rank order within a suit that is not trump: A 10 K O U 9 8 7, or in a
+    // Bettel A K O U 10 9 8 7
+    fn plain_strength(&self, rank: Rank) -> u8 {
+        match self {
+            Gamemode::Bettel => bettel_strength(rank),
+            _ => non_trump_strength(rank),
+        }
+    }
+
+    fn trump_rank(&self, card: &Card) -> u16 {
+        match self {
+            Gamemode::Bettel => 0,
+            Gamemode::Sauspiel(_) | Gamemode::Ramsch =>
+                trump_strength(card, Suit::Herz),
+            Gamemode::Solo(solo_suit) => trump_strength(card, *solo_suit),
+            Gamemode::Wenz(wenz_suit) => trump_strength_wenz(card, Rank::Unter, *wenz_suit),
+            Gamemode::Geier(geier_suit) => trump_strength_wenz(card, Rank::Ober, *geier_suit),
+        }
+    }
 }
 
 fn winner_for_wenz(rank: Rank, trump_suit: Option<Suit>, cards: [&Card; 4]) -> &Card  {
@@ -34,15 +171,7 @@ fn winner_for_wenz(rank: Rank, trump_suit: Option<Suit>, cards: [&Card; 4]) -> &
             .unwrap_or(0);
         cards[winner_idx]
     } else {
-        let first_suit = cards[0].suit;
-        let winner_idx = cards
-            .iter()
-            .enumerate()
-            .filter(|&(_, &c)| c.suit == first_suit)
-            .max_by_key(|&(_, &c)| non_trump_strength(c.rank))
-            .map(|(i, _)| i)
-            .unwrap_or(0);
-        cards[winner_idx]
+        winner_of_led_suit(cards, non_trump_strength)
     }
 }
 
@@ -59,19 +188,23 @@ fn winner_for_trump(trump_suit: Suit, cards: [&Card; 4]) -> &Card {
             .unwrap_or(0);
         cards[winner_idx]
     } else {
-        // No trump: highest of the led suit wins
-        let first_suit = cards[0].suit;
-        let winner_idx = cards
-            .iter()
-            .enumerate()
-            .filter(|&(_, &c)| c.suit == first_suit)
-            .max_by_key(|&(_, &c)| non_trump_strength(c.rank))
-            .map(|(i, _)| i)
-            .unwrap_or(0);
-        cards[winner_idx]
+        winner_of_led_suit(cards, non_trump_strength)
     }
 }
 
+// No trump in the trick: the highest card of the led suit by `strength` wins
+fn winner_of_led_suit(cards: [&Card; 4], strength: fn(Rank) -> u8) -> &Card {
+    let first_suit = cards[0].suit;
+    let winner_idx = cards
+        .iter()
+        .enumerate()
+        .filter(|&(_, &c)| c.suit == first_suit)
+        .max_by_key(|&(_, &c)| strength(c.rank))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    cards[winner_idx]
+}
+
 fn is_trump(card: &Card, trump_ranks: &[Rank], trump_suit: Option<Suit>) -> bool {
     trump_ranks.contains(&card.rank) || (trump_suit == Some(card.suit))
 }
@@ -121,5 +254,18 @@ fn non_trump_strength(rank: Rank) -> u8 {
     }
 }
 
+fn bettel_strength(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ass    => 8,
+        Rank::Koenig => 7,
+        Rank::Ober   => 6,
+        Rank::Unter  => 5,
+        Rank::Zehn   => 4,
+        Rank::Neun   => 3,
+        Rank::Acht   => 2,
+        Rank::Sieben => 1,
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,125 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::deck::Suit;
+use crate::gamemode::{Announcement, Gamemode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGamemodeError {
+    input: String,
+}
+
+impl ParseGamemodeError {
+    pub(crate) fn new(input: &str) -> Self {
+        Self { input: input.to_string() }
+    }
+}
+
+impl fmt::Display for ParseGamemodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown game mode: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseGamemodeError {}
+
+// Nicknames of the Sau that is called in a Sauspiel; the Herz-Sau is a
+// trump and never called
+pub(crate) fn sau_name(suit: Suit) -> Option<&'static str> {
+    match suit {
+        Suit::Eichel    => Some("Alte"),
+        Suit::Gras      => Some("Blaue"),
+        Suit::Herz      => None,
+        Suit::Schell    => Some("Hundsgfickte"),
+    }
+}
+
+fn parse_suit(s: &str) -> Option<Suit> {
+    [Suit::Eichel, Suit::Gras, Suit::Herz, Suit::Schell]
+        .into_iter()
+        .find(|suit| suit.to_string().eq_ignore_ascii_case(s))
+}
+
+// The suit of a Sauspiel, by the Sau's nickname or the suit; not Herz
+fn parse_called_suit(s: &str) -> Option<Suit> {
+    [Suit::Eichel, Suit::Gras, Suit::Schell]
+        .into_iter()
+        .find(|&suit| sau_name(suit).is_some_and(|name| name.eq_ignore_ascii_case(s)))
+        .or_else(|| parse_suit(s).filter(|&suit| suit != Suit::Herz))
+}
+
+impl fmt::Display for Gamemode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gamemode::Sauspiel(suit)    => write!(f, "Sauspiel auf die {}", sau_name(*suit).unwrap_or("Herz")),
+            Gamemode::Solo(suit)        => write!(f, "{}-Solo", suit),
+            Gamemode::Wenz(None)        => write!(f, "Wenz"),
+            Gamemode::Wenz(Some(suit))  => write!(f, "Farbwenz {}", suit),
+            Gamemode::Geier(None)       => write!(f, "Geier"),
+            Gamemode::Geier(Some(suit)) => write!(f, "Farbgeier {}", suit),
+            Gamemode::Bettel            => write!(f, "Bettel"),
+            Gamemode::Ramsch            => write!(f, "Ramsch"),
+        }
+    }
+}
+
+/// Parses the names produced by `Display`, ignoring ASCII case.
+///
+/// A Sauspiel also accepts the suit instead of the Sau's nickname, e.g.
+/// "Sauspiel auf die Gras", but never Herz, which is trump.
+impl FromStr for Gamemode {
+    type Err = ParseGamemodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseGamemodeError { input: s.to_string() };
+        let lower = s.trim().to_lowercase();
+
+        let mode = match lower.as_str() {
+            "wenz" => Gamemode::Wenz(None),
+            "geier" => Gamemode::Geier(None),
+            "bettel" => Gamemode::Bettel,
+            "ramsch" => Gamemode::Ramsch,
+            _ => {
+                if let Some(sau) = lower.strip_prefix("sauspiel auf die ") {
+                    Gamemode::Sauspiel(parse_called_suit(sau).ok_or_else(err)?)
+                } else if let Some(suit) = lower.strip_suffix("-solo") {
+                    Gamemode::Solo(parse_suit(suit).ok_or_else(err)?)
+                } else if let Some(suit) = lower.strip_prefix("farbwenz ") {
+                    Gamemode::Wenz(Some(parse_suit(suit).ok_or_else(err)?))
+                } else if let Some(suit) = lower.strip_prefix("farbgeier ") {
+                    Gamemode::Geier(Some(parse_suit(suit).ok_or_else(err)?))
+                } else {
+                    return Err(err());
+                }
+            }
+        };
+        Ok(mode)
+    }
+}
+
+impl fmt::Display for Announcement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Announcement::Game(mode)    => write!(f, "{}", mode),
+            Announcement::Tout(mode)    => write!(f, "{} Tout", mode),
+            Announcement::Sie(mode)     => write!(f, "{} Sie", mode),
+        }
+    }
+}
+
+impl FromStr for Announcement {
+    type Err = ParseGamemodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |_| ParseGamemodeError { input: s.to_string() };
+        let lower = s.trim().to_lowercase();
+
+        if let Some(mode) = lower.strip_suffix(" tout") {
+            mode.parse().map(Announcement::Tout).map_err(err)
+        } else if let Some(mode) = lower.strip_suffix(" sie") {
+            mode.parse().map(Announcement::Sie).map_err(err)
+        } else {
+            lower.parse().map(Announcement::Game).map_err(err)
+        }
+    }
+}
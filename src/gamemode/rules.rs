@@ -0,0 +1,239 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use crate::deck::{Card, Deck, Rank};
+use crate::gamemode::{Announcement, Gamemode, TariffClass};
+
+/// The rules a game is played by.
+///
+/// Implement this to play house variants with the engine in [`crate::game`];
+/// [`Gamemode`] and [`Announcement`] implement it for the standard games.
+/// Only trump membership and card strength are required, everything else
+/// follows the usual Schafkopf rules unless overridden.
+///
+/// The rules hold no state of their own. The engine passes in everything a
+/// decision may depend on: the hand, the unfinished trick and the cards of
+/// the finished tricks.
+pub trait GameRules: fmt::Display + Send + Sync {
+    fn is_trump(&self, card: &Card) -> bool;
+
+    /// Strength of `card` in a trick that was opened with `led`.
+    ///
+    /// Trumps must be stronger than any card of a led suit, and a card that
+    /// neither is a trump nor follows the led suit must return 0.
+    fn card_strength(&self, card: &Card, led: &Card) -> u16;
+
+    /// Whether `card` follows the suit (or trump) of `led`.
+    fn follows(&self, card: &Card, led: &Card) -> bool {
+        if self.is_trump(led) {
+            self.is_trump(card)
+        } else {
+            !self.is_trump(card) && card.suit == led.suit
+        }
+    }
+
+    /// Index of the card that takes `trick`.
+    fn winning_index(&self, trick: &[Card]) -> usize {
+        trick
+            .iter()
+            .enumerate()
+            .max_by_key(|&(i, c)| (self.card_strength(c, &trick[0]), Reverse(i)))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    /// Cards of `hand` that may be played onto the unfinished `trick`.
+    ///
+    /// `_played` holds the cards of the finished tricks in the order they
+    /// were played, four per trick with the lead first.
+    fn legal_cards(&self, hand: &[Card], trick: &[Card], _played: &[Card]) -> Vec<Card> {
+        follow_suit(self, hand, trick)
+    }
+
+    /// All trumps, strongest first.
+    fn trump_order(&self) -> Vec<Card> {
+        let mut trumps: Vec<Card> = Deck::new().iter().copied().filter(|c| self.is_trump(c)).collect();
+        trumps.sort_by_key(|c| Reverse(self.card_strength(c, c)));
+        trumps
+    }
+
+    /// The card whose holder becomes the declarer's partner, if any.
+    fn called_card(&self) -> Option<Card> {
+        None
+    }
+
+    /// Whether the game has a declarer at all (not so in Ramsch).
+    fn has_declarer(&self) -> bool {
+        true
+    }
+
+    fn card_points(&self, card: &Card) -> u8 {
+        card.rank.points()
+    }
+
+    /// Whether the declaring side won having taken `points` in `tricks` tricks.
+    fn declarer_wins(&self, points: u8, _tricks: usize) -> bool {
+        points > 60
+    }
+
+    /// The base tariff the game is paid with.
+    fn tariff_class(&self) -> TariffClass {
+        if self.has_declarer() && self.called_card().is_none() { TariffClass::Solo } else { TariffClass::Normal }
+    }
+
+    /// Factor the whole price is multiplied by.
+    fn multiplier(&self) -> i64 {
+        1
+    }
+
+    /// Fewest Laufende that are paid, `None` if the game pays none.
+    fn laufende_from(&self) -> Option<usize> {
+        Some(3)
+    }
+}
+
+impl GameRules for Gamemode {
+    fn is_trump(&self, card: &Card) -> bool {
+        Gamemode::is_trump(self, card)
+    }
+
+    /// Bettel has no trumps and ranks each suit A K O U 10 9 8 7, with Ober
+    /// and Unter as suit cards and the Zehn between Unter and Neun.
+    fn card_strength(&self, card: &Card, led: &Card) -> u16 {
+        if self.is_trump(card) {
+            1000 + self.trump_rank(card)
+        } else if self.follows(card, led) {
+            self.plain_strength(card.rank) as u16
+        } else {
+            0
+        }
+    }
+
+    /// Besides following suit, a Sauspiel restricts the holder of the called
+    /// Sau: it must be played when its suit is led, it may not be discarded
+    /// before the last trick, and its suit may only be led with the Sau
+    /// itself unless the hand holds four or more cards of that suit
+    /// (davonlaufen). Once the holder has run away and the suit has been
+    /// led, the Sau is an ordinary card again.
+    fn legal_cards(&self, hand: &[Card], trick: &[Card], played: &[Card]) -> Vec<Card> {
+        let Some(sau) = self.called_card().filter(|sau| hand.contains(sau)) else {
+            return follow_suit(self, hand, trick);
+        };
+        if played.chunks(4).any(|t| self.follows(&t[0], &sau)) {
+            return follow_suit(self, hand, trick);
+        }
+        let called_suit: Vec<Card> = hand.iter().copied().filter(|c| self.follows(c, &sau)).collect();
+
+        match trick.first() {
+            None if called_suit.len() >= 4 => hand.to_vec(),
+            None => hand.iter().copied().filter(|c| *c == sau || !called_suit.contains(c)).collect(),
+            Some(led) if self.follows(led, &sau) => vec![sau],
+            Some(_) => {
+                let legal = follow_suit(self, hand, trick);
+                if legal.len() > 1 {
+                    legal.into_iter().filter(|c| *c != sau).collect()
+                } else {
+                    legal
+                }
+            }
+        }
+    }
+
+    fn trump_order(&self) -> Vec<Card> {
+        self.trumps()
+    }
+
+    fn called_card(&self) -> Option<Card> {
+        match self {
+            Gamemode::Sauspiel(suit) => Some(Card { suit: *suit, rank: Rank::Ass }),
+            _ => None,
+        }
+    }
+
+    fn has_declarer(&self) -> bool {
+        !matches!(self, Gamemode::Ramsch)
+    }
+
+    /// A Bettel is won by taking no trick at all.
+    fn declarer_wins(&self, points: u8, tricks: usize) -> bool {
+        match self {
+            Gamemode::Bettel => tricks == 0,
+            _ => points > 60,
+        }
+    }
+
+    fn tariff_class(&self) -> TariffClass {
+        Gamemode::tariff_class(self)
+    }
+
+    /// Wenz and Geier pay from two Laufende, Bettel and Ramsch none.
+    fn laufende_from(&self) -> Option<usize> {
+        match self {
+            Gamemode::Bettel | Gamemode::Ramsch => None,
+            Gamemode::Wenz(None) | Gamemode::Geier(None) => Some(2),
+            _ => Some(3),
+        }
+    }
+}
+
+/// Cards of `hand` that follow the suit (or trump) led in `trick`, or the
+/// whole hand if it cannot follow.
+pub fn follow_suit<R: GameRules + ?Sized>(rules: &R, hand: &[Card], trick: &[Card]) -> Vec<Card> {
+    let Some(led) = trick.first() else {
+        return hand.to_vec();
+    };
+    let following: Vec<Card> = hand.iter().copied().filter(|c| rules.follows(c, led)).collect();
+    if following.is_empty() { hand.to_vec() } else { following }
+}
+
+/// Tout and Sie are played by the rules of their game but are only won by
+/// taking every trick.
+impl GameRules for Announcement {
+    fn is_trump(&self, card: &Card) -> bool {
+        self.gamemode().is_trump(card)
+    }
+
+    fn card_strength(&self, card: &Card, led: &Card) -> u16 {
+        GameRules::card_strength(self.gamemode(), card, led)
+    }
+
+    fn legal_cards(&self, hand: &[Card], trick: &[Card], played: &[Card]) -> Vec<Card> {
+        self.gamemode().legal_cards(hand, trick, played)
+    }
+
+    fn trump_order(&self) -> Vec<Card> {
+        self.gamemode().trump_order()
+    }
+
+    fn called_card(&self) -> Option<Card> {
+        self.gamemode().called_card()
+    }
+
+    fn has_declarer(&self) -> bool {
+        self.gamemode().has_declarer()
+    }
+
+    fn declarer_wins(&self, points: u8, tricks: usize) -> bool {
+        match self {
+            Announcement::Game(mode) => mode.declarer_wins(points, tricks),
+            Announcement::Tout(_) | Announcement::Sie(_) => tricks == 8,
+        }
+    }
+
+    fn tariff_class(&self) -> TariffClass {
+        self.gamemode().tariff_class()
+    }
+
+    /// Tout pays double, Sie four times.
+    fn multiplier(&self) -> i64 {
+        match self {
+            Announcement::Game(_) => 1,
+            Announcement::Tout(_) => 2,
+            Announcement::Sie(_) => 4,
+        }
+    }
+
+    fn laufende_from(&self) -> Option<usize> {
+        self.gamemode().laufende_from()
+    }
+}
@@ -35,7 +35,7 @@ fn winner_test_1() {
     assert_eq!(winner, &c2);
 
     let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]);
-    assert_eq!(winner, &c2);
+    assert_eq!(winner, &c1);
 
     let winner = Gamemode::Ramsch.winning_card([&c1, &c2, &c3, &c4]);
     assert_eq!(winner, &c2);
@@ -65,9 +65,9 @@ fn sauspiel_trump_hierarchy() {
     let winner = Gamemode::Solo(Suit::Schell).winning_card([&c1, &c2, &c3, &c4]);
     assert_eq!(winner, &c2);
 
-    // Herz-trump modes equivalent
+    // Bettel has no trumps, Ramsch plays with Herz
     let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]);
-    assert_eq!(winner, &c2);
+    assert_eq!(winner, &c1);
     let winner = Gamemode::Ramsch.winning_card([&c1, &c2, &c3, &c4]);
     assert_eq!(winner, &c2);
 }
@@ -93,8 +93,8 @@ fn sauspiel_ober_suit_precedence() {
     let winner = Gamemode::Wenz(None).winning_card([&c1, &c2, &c3, &c4]); // only Unter trump
     assert_eq!(winner, &c4);
 
-    let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]); // Herz-trump
-    assert_eq!(winner, &c2);
+    let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]); // no trumps
+    assert_eq!(winner, &c1);
 }
 
 #[test]
@@ -251,18 +251,23 @@ fn geier_ober_trumps_over_optional_suit_trump() {
 }
 
 #[test]
-fn bettel_behaves_like_herz_trump() {
-    // Current implementation treats Bettel like Herz-trump
+fn bettel_has_no_trumps() {
+    // Bettel is played without trumps: only the led suit can take the trick
     let c1 = card(Suit::Gras, Rank::Ass);        // led
-    let c2 = card(Suit::Herz, Rank::Neun);       // trump by Herz suit
+    let c2 = card(Suit::Herz, Rank::Neun);       // plain Herz
     let c3 = card(Suit::Schell, Rank::Ass);
     let c4 = card(Suit::Eichel, Rank::Koenig);
 
     let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]);
-    assert_eq!(winner, &c2);
+    assert_eq!(winner, &c1);
+
+    // The Zehn ranks below the Unter
+    let (zehn, unter) = (card(Suit::Gras, Rank::Zehn), card(Suit::Gras, Rank::Unter));
+    assert_eq!(Gamemode::Bettel.winning_card([&zehn, &c2, &unter, &c4]), &unter);
+    assert!(Gamemode::Bettel.card_strength(&unter, &zehn) > Gamemode::Bettel.card_strength(&zehn, &zehn));
 
     // More checks:
-    let winner = Gamemode::Ramsch.winning_card([&c1, &c2, &c3, &c4]); // same as Bettel currently
+    let winner = Gamemode::Ramsch.winning_card([&c1, &c2, &c3, &c4]); // Herz trump
     assert_eq!(winner, &c2);
 
     let winner = Gamemode::Sauspiel(Suit::Eichel).winning_card([&c1, &c2, &c3, &c4]); // Herz trump
@@ -293,8 +298,8 @@ fn ramsch_behaves_like_herz_trump() {
     assert_eq!(winner, &c3);
 
     // More checks:
-    let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]); // same as Ramsch currently
-    assert_eq!(winner, &c3);
+    let winner = Gamemode::Bettel.winning_card([&c1, &c2, &c3, &c4]); // no trumps
+    assert_eq!(winner, &c1);
 
     let winner = Gamemode::Sauspiel(Suit::Eichel).winning_card([&c1, &c2, &c3, &c4]); // Herz trump
     assert_eq!(winner, &c3);
@@ -310,4 +315,340 @@ fn ramsch_behaves_like_herz_trump() {
 
     let winner = Gamemode::Geier(Some(Suit::Herz)).winning_card([&c1, &c2, &c3, &c4]); // suit trump Herz
     assert_eq!(winner, &c3);
-}
\ No newline at end of file
+}
+
+#[test]
+fn trumps_are_listed_strongest_first() {
+    let trumps = Gamemode::Sauspiel(Suit::Eichel).trumps();
+    assert_eq!(trumps.len(), 14);
+    assert_eq!(trumps[0], card(Suit::Eichel, Rank::Ober));
+    assert_eq!(trumps[7], card(Suit::Schell, Rank::Unter));
+    assert_eq!(trumps[8], card(Suit::Herz, Rank::Ass));
+    assert_eq!(trumps[13], card(Suit::Herz, Rank::Sieben));
+
+    let trumps = Gamemode::Wenz(None).trumps();
+    assert_eq!(trumps.len(), 4);
+    assert!(trumps.iter().all(|c| c.rank == Rank::Unter));
+
+    let trumps = Gamemode::Geier(Some(Suit::Gras)).trumps();
+    assert_eq!(trumps.len(), 11);
+    assert_eq!(trumps[4], card(Suit::Gras, Rank::Ass));
+}
+
+#[test]
+fn sauspiel_only_callable_for_held_suits_without_sau() {
+    let hand = [
+        card(Suit::Eichel, Rank::Ober),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Herz, Rank::Ass),
+        card(Suit::Eichel, Rank::Ass),
+        card(Suit::Eichel, Rank::Neun),
+        card(Suit::Gras, Rank::Koenig),
+        card(Suit::Herz, Rank::Acht),
+        card(Suit::Herz, Rank::Sieben),
+    ];
+
+    let called: Vec<Suit> = available_announcements(&hand, &Ruleset::default())
+        .iter()
+        .filter_map(|a| match a {
+            Announcement::Game(Gamemode::Sauspiel(suit)) => Some(*suit),
+            _ => None,
+        })
+        .collect();
+
+    // Eichel: own Sau, Schell: no card of the suit (Gras Unter is a trump)
+    assert_eq!(called, vec![Suit::Gras]);
+}
+
+#[test]
+fn ruleset_limits_solo_announcements() {
+    let hand = [
+        card(Suit::Eichel, Rank::Sieben),
+        card(Suit::Gras, Rank::Sieben),
+        card(Suit::Herz, Rank::Sieben),
+        card(Suit::Schell, Rank::Sieben),
+        card(Suit::Eichel, Rank::Acht),
+        card(Suit::Gras, Rank::Acht),
+        card(Suit::Herz, Rank::Acht),
+        card(Suit::Schell, Rank::Acht),
+    ];
+
+    let classic = available_announcements(&hand, &Ruleset::default());
+    assert!(classic.iter().any(|a| matches!(a, Announcement::Game(Gamemode::Wenz(None)))));
+    assert!(classic.iter().any(|a| matches!(a, Announcement::Tout(Gamemode::Solo(Suit::Herz)))));
+    assert!(!classic.iter().any(|a| matches!(a.gamemode(), Gamemode::Geier(_) | Gamemode::Bettel)));
+    assert!(!classic.iter().any(|a| matches!(a, Announcement::Sie(_))));
+
+    let no_tout = Ruleset { tout: false, ..Ruleset::default() };
+    assert!(!available_announcements(&hand, &no_tout).iter().any(|a| matches!(a, Announcement::Tout(_))));
+
+    let everything = available_announcements(&hand, &Ruleset::all());
+    assert!(everything.iter().any(|a| matches!(a, Announcement::Game(Gamemode::Bettel))));
+    assert!(everything.iter().any(|a| matches!(a, Announcement::Game(Gamemode::Geier(Some(Suit::Schell))))));
+}
+
+#[test]
+fn sie_requires_all_ober_and_unter() {
+    let mut hand: Vec<Card> = [Suit::Eichel, Suit::Gras, Suit::Herz, Suit::Schell]
+        .iter()
+        .flat_map(|&s| [card(s, Rank::Ober), card(s, Rank::Unter)])
+        .collect();
+
+    let announcements = available_announcements(&hand, &Ruleset::default());
+    let sie = announcements.iter().filter(|a| matches!(a, Announcement::Sie(Gamemode::Solo(_)))).count();
+    assert_eq!(sie, 4);
+
+    hand[7] = card(Suit::Herz, Rank::Ass);
+    let announcements = available_announcements(&hand, &Ruleset::default());
+    assert!(!announcements.iter().any(|a| matches!(a, Announcement::Sie(_))));
+}
+
+fn sample_hand() -> Vec<Card> {
+    vec![
+        card(Suit::Schell, Rank::Sieben),
+        card(Suit::Herz, Rank::Koenig),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Eichel, Rank::Zehn),
+        card(Suit::Herz, Rank::Ober),
+        card(Suit::Eichel, Rank::Ass),
+        card(Suit::Schell, Rank::Ober),
+        card(Suit::Gras, Rank::Neun),
+    ]
+}
+
+#[test]
+fn sort_hand_puts_trumps_first() {
+    let mut hand = sample_hand();
+    Gamemode::Solo(Suit::Schell).sort_hand(&mut hand);
+    assert_eq!(hand, vec![
+        card(Suit::Herz, Rank::Ober),
+        card(Suit::Schell, Rank::Ober),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Schell, Rank::Sieben),
+        card(Suit::Eichel, Rank::Ass),
+        card(Suit::Eichel, Rank::Zehn),
+        card(Suit::Gras, Rank::Neun),
+        card(Suit::Herz, Rank::Koenig),
+    ]);
+
+    let mut neutral = sample_hand();
+    Gamemode::sort_neutral(&mut neutral);
+    assert_eq!(neutral[3], card(Suit::Herz, Rank::Koenig));
+    assert_eq!(neutral[7], card(Suit::Schell, Rank::Sieben));
+}
+
+#[test]
+fn sort_hand_moves_ober_back_into_suits_for_wenz() {
+    let mut hand = sample_hand();
+    Gamemode::Wenz(None).sort_hand(&mut hand);
+    assert_eq!(hand, vec![
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Eichel, Rank::Ass),
+        card(Suit::Eichel, Rank::Zehn),
+        card(Suit::Gras, Rank::Neun),
+        card(Suit::Herz, Rank::Koenig),
+        card(Suit::Herz, Rank::Ober),
+        card(Suit::Schell, Rank::Ober),
+        card(Suit::Schell, Rank::Sieben),
+    ]);
+
+    let mut hand = sample_hand();
+    Gamemode::Geier(None).sort_hand(&mut hand);
+    assert_eq!(&hand[..2], &[card(Suit::Herz, Rank::Ober), card(Suit::Schell, Rank::Ober)]);
+    assert_eq!(hand[4], card(Suit::Gras, Rank::Unter));
+}
+
+#[test]
+fn sort_hand_bettel_has_no_trumps() {
+    let mut hand = sample_hand();
+    Gamemode::Bettel.sort_hand(&mut hand);
+    assert_eq!(hand, vec![
+        card(Suit::Eichel, Rank::Ass),
+        card(Suit::Eichel, Rank::Zehn),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Gras, Rank::Neun),
+        card(Suit::Herz, Rank::Koenig),
+        card(Suit::Herz, Rank::Ober),
+        card(Suit::Schell, Rank::Ober),
+        card(Suit::Schell, Rank::Sieben),
+    ]);
+
+    let mut gras = vec![
+        card(Suit::Gras, Rank::Zehn),
+        card(Suit::Gras, Rank::Neun),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Gras, Rank::Ass),
+    ];
+    Gamemode::Bettel.sort_hand(&mut gras);
+    assert_eq!(gras, vec![
+        card(Suit::Gras, Rank::Ass),
+        card(Suit::Gras, Rank::Unter),
+        card(Suit::Gras, Rank::Zehn),
+        card(Suit::Gras, Rank::Neun),
+    ]);
+}
+
+#[test]
+fn gamemode_names() {
+    assert_eq!(Gamemode::Solo(Suit::Herz).to_string(), "Herz-Solo");
+    assert_eq!(Gamemode::Wenz(Some(Suit::Gras)).to_string(), "Farbwenz Gras");
+    assert_eq!(Gamemode::Sauspiel(Suit::Gras).to_string(), "Sauspiel auf die Blaue");
+    assert_eq!(Gamemode::Sauspiel(Suit::Eichel).to_string(), "Sauspiel auf die Alte");
+    assert_eq!(Announcement::Tout(Gamemode::Wenz(None)).to_string(), "Wenz Tout");
+}
+
+#[test]
+fn gamemode_names_round_trip() {
+    let mut modes = vec![Gamemode::Wenz(None), Gamemode::Geier(None), Gamemode::Bettel, Gamemode::Ramsch];
+    for suit in [Suit::Eichel, Suit::Gras, Suit::Herz, Suit::Schell] {
+        modes.extend([Gamemode::Solo(suit), Gamemode::Wenz(Some(suit)), Gamemode::Geier(Some(suit))]);
+        if suit != Suit::Herz {
+            modes.push(Gamemode::Sauspiel(suit));
+        }
+    }
+    for mode in modes {
+        assert_eq!(mode.to_string().parse::<Gamemode>(), Ok(mode));
+        for announcement in [Announcement::Game(mode), Announcement::Tout(mode), Announcement::Sie(mode)] {
+            assert_eq!(announcement.to_string().parse::<Announcement>(), Ok(announcement));
+        }
+    }
+
+    assert_eq!("sauspiel auf die schell".parse(), Ok(Gamemode::Sauspiel(Suit::Schell)));
+    assert_eq!(" EICHEL-SOLO ".parse(), Ok(Gamemode::Solo(Suit::Eichel)));
+    assert!("Laub-Solo".parse::<Gamemode>().is_err());
+    assert!("Bettel Sie".parse::<Gamemode>().is_err());
+
+    // Herz is trump, its Sau cannot be called
+    assert!("Sauspiel auf die Herz".parse::<Gamemode>().is_err());
+    assert!("Sauspiel auf die Rote".parse::<Gamemode>().is_err());
+}
+
+#[test]
+fn gamemode_metadata() {
+    assert_eq!(Gamemode::Sauspiel(Suit::Gras).parties(), Parties::Partnership);
+    assert_eq!(Gamemode::Ramsch.parties(), Parties::FreeForAll);
+    assert!(Gamemode::Bettel.is_solo());
+    assert!(Gamemode::Geier(Some(Suit::Herz)).is_solo());
+
+    assert_eq!(Gamemode::Sauspiel(Suit::Gras).tariff_class(), TariffClass::Normal);
+    assert_eq!(Gamemode::Ramsch.tariff_class(), TariffClass::Normal);
+    assert_eq!(Gamemode::Wenz(None).tariff_class(), TariffClass::Solo);
+
+    assert!(Gamemode::Solo(Suit::Schell).precedence() > Gamemode::Wenz(None).precedence());
+    assert!(Gamemode::Wenz(None).precedence() > Gamemode::Wenz(Some(Suit::Herz)).precedence());
+    assert!(Gamemode::Bettel.precedence() > Gamemode::Sauspiel(Suit::Eichel).precedence());
+    assert!(Announcement::Tout(Gamemode::Geier(Some(Suit::Gras))).precedence()
+        > Announcement::Game(Gamemode::Solo(Suit::Eichel)).precedence());
+    assert!(Announcement::Sie(Gamemode::Solo(Suit::Schell)).precedence()
+        > Announcement::Tout(Gamemode::Solo(Suit::Eichel)).precedence());
+}
+
+fn all_modes() -> Vec<Gamemode> {
+    let mut modes = vec![Gamemode::Wenz(None), Gamemode::Geier(None), Gamemode::Bettel, Gamemode::Ramsch];
+    for suit in [Suit::Eichel, Suit::Gras, Suit::Herz, Suit::Schell] {
+        modes.extend([Gamemode::Sauspiel(suit), Gamemode::Solo(suit),
+                      Gamemode::Wenz(Some(suit)), Gamemode::Geier(Some(suit))]);
+    }
+    modes
+}
+
+#[test]
+fn game_rules_winner_matches_winning_card() {
+    let deck: Vec<Card> = Deck::new().iter().copied().collect();
+    for mode in all_modes() {
+        for start in 0..32 {
+            for step in [1, 3, 5, 7, 9, 11] {
+                let trick: Vec<Card> = (0..4).map(|i| deck[(start + i * step) % 32]).collect();
+                let winner = mode.winning_card([&trick[0], &trick[1], &trick[2], &trick[3]]);
+                assert_eq!(&trick[mode.winning_index(&trick)], winner, "{} {:?}", mode, trick);
+            }
+        }
+    }
+}
+
+#[test]
+fn game_rules_trump_order_matches_trumps() {
+    for mode in all_modes() {
+        assert_eq!(GameRules::trump_order(&mode), mode.trumps());
+    }
+}
+
+#[test]
+fn legal_cards_follow_suit_and_trump() {
+    let hand = [
+        card(Suit::Herz, Rank::Neun),
+        card(Suit::Eichel, Rank::Unter),
+        card(Suit::Gras, Rank::Koenig),
+        card(Suit::Gras, Rank::Ober),
+    ];
+    let mode = Gamemode::Solo(Suit::Herz);
+
+    // Trump led: Herz and Ober/Unter
+    let legal = mode.legal_cards(&hand, &[card(Suit::Schell, Rank::Ober)], &[]);
+    assert_eq!(legal, vec![hand[0], hand[1], hand[3]]);
+
+    // Gras led: the Gras Ober is a trump and does not follow
+    let legal = mode.legal_cards(&hand, &[card(Suit::Gras, Rank::Ass)], &[]);
+    assert_eq!(legal, vec![hand[2]]);
+
+    // Schell led and none held: anything goes
+    let legal = mode.legal_cards(&hand, &[card(Suit::Schell, Rank::Ass)], &[]);
+    assert_eq!(legal, hand.to_vec());
+
+    // In a Wenz the Gras Ober follows Gras
+    let legal = Gamemode::Wenz(None).legal_cards(&hand, &[card(Suit::Gras, Rank::Ass)], &[]);
+    assert_eq!(legal, vec![hand[2], hand[3]]);
+}
+
+#[test]
+fn legal_cards_restrict_called_sau() {
+    let mode = Gamemode::Sauspiel(Suit::Schell);
+    let sau = card(Suit::Schell, Rank::Ass);
+    let hand = [sau, card(Suit::Schell, Rank::Neun), card(Suit::Eichel, Rank::Acht), card(Suit::Herz, Rank::Ober)];
+
+    // Leading: the called suit only with the Sau
+    assert_eq!(mode.legal_cards(&hand, &[], &[]), vec![hand[0], hand[2], hand[3]]);
+
+    // Called suit led: the Sau must be played
+    assert_eq!(mode.legal_cards(&hand, &[card(Suit::Schell, Rank::Koenig)], &[]), vec![sau]);
+
+    // Void in Gras: the Sau may not be discarded
+    assert_eq!(mode.legal_cards(&hand, &[card(Suit::Gras, Rank::Koenig)], &[]),
+               vec![hand[1], hand[2], hand[3]]);
+
+    // Davonlaufen with four cards of the called suit
+    let hand = [sau, card(Suit::Schell, Rank::Neun), card(Suit::Schell, Rank::Acht), card(Suit::Schell, Rank::Sieben)];
+    assert_eq!(mode.legal_cards(&hand, &[], &[]), hand.to_vec());
+
+    // Everyone else follows the normal rules
+    let hand = [card(Suit::Schell, Rank::Neun), card(Suit::Eichel, Rank::Acht)];
+    assert_eq!(mode.legal_cards(&hand, &[], &[]), hand.to_vec());
+}
+
+#[test]
+fn called_sau_is_free_once_its_holder_ran_away() {
+    let mode = Gamemode::Sauspiel(Suit::Schell);
+    let sau = card(Suit::Schell, Rank::Ass);
+    let hand = [sau, card(Suit::Schell, Rank::Acht), card(Suit::Eichel, Rank::Acht)];
+    // The holder led the Schell Neun from four Schell
+    let played = [
+        card(Suit::Schell, Rank::Neun),
+        card(Suit::Schell, Rank::Koenig),
+        card(Suit::Herz, Rank::Ober),
+        card(Suit::Schell, Rank::Zehn),
+    ];
+
+    assert_eq!(mode.legal_cards(&hand, &[card(Suit::Schell, Rank::Sieben)], &played), vec![sau, hand[1]]);
+    assert_eq!(mode.legal_cards(&hand, &[card(Suit::Gras, Rank::Koenig)], &played), hand.to_vec());
+    assert_eq!(mode.legal_cards(&hand, &[], &played), hand.to_vec());
+}
+
+#[test]
+fn declarer_wins_per_mode() {
+    assert!(Gamemode::Solo(Suit::Gras).declarer_wins(61, 3));
+    assert!(!Gamemode::Solo(Suit::Gras).declarer_wins(60, 5));
+    assert!(Gamemode::Bettel.declarer_wins(0, 0));
+    assert!(!Gamemode::Bettel.declarer_wins(0, 1));
+    assert!(!Announcement::Tout(Gamemode::Wenz(None)).declarer_wins(119, 7));
+    assert!(Announcement::Sie(Gamemode::Solo(Suit::Herz)).declarer_wins(120, 8));
+}
@@ -1,3 +1,9 @@
+pub mod advisor;
 pub mod deck;
+pub mod game;
 pub mod gamemode;
+pub mod locale;
 pub mod player;
+pub mod solver;
+pub mod table;
+pub mod tracker;
@@ -0,0 +1,171 @@
+use strum::IntoEnumIterator;
+
+use crate::deck::{Card, ParseCardError, Rank, Suit};
+use crate::gamemode::{Announcement, Gamemode, ParseGamemodeError, sau_name};
+
+/// A language to name cards, game modes and announcements in.
+///
+/// Every name a locale renders can be parsed back by the same locale,
+/// ignoring case. A leading article may be left out, so "die Alte" and
+/// "alte" both read as the Eichel-Sau in Bavarian, while "der Alte" is the
+/// Eichel-Ober.
+///
+/// ```
+/// use schafkopf_logic::deck::{Card, Rank, Suit};
+/// use schafkopf_logic::locale::Locale;
+///
+/// let alte = Card { suit: Suit::Eichel, rank: Rank::Ass };
+/// assert_eq!(Locale::Bavarian.card(alte), "die Alte");
+/// assert_eq!(Locale::English.card(alte), "Ace of Acorns");
+/// assert_eq!(Locale::Bavarian.parse_card("Alte"), Ok(alte));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// The names used by `Display` throughout the crate.
+    #[default]
+    German,
+    English,
+    Bavarian,
+}
+
+impl Locale {
+    pub fn suit(self, suit: Suit) -> &'static str {
+        match (self, suit) {
+            (Locale::German, _)                 => suit.name(),
+            (Locale::English, Suit::Eichel)     => "Acorns",
+            (Locale::English, Suit::Gras)       => "Leaves",
+            (Locale::English, Suit::Herz)       => "Hearts",
+            (Locale::English, Suit::Schell)     => "Bells",
+            (Locale::Bavarian, Suit::Eichel)    => "Oachl",
+            (Locale::Bavarian, Suit::Gras)      => "Gras",
+            (Locale::Bavarian, Suit::Herz)      => "Herz",
+            (Locale::Bavarian, Suit::Schell)    => "Schellen",
+        }
+    }
+
+    pub fn rank(self, rank: Rank) -> &'static str {
+        match (self, rank) {
+            (Locale::German, _)                 => rank.name(),
+            (Locale::English, Rank::Ass)        => "Ace",
+            (Locale::English, Rank::Zehn)       => "Ten",
+            (Locale::English, Rank::Koenig)     => "King",
+            (Locale::English, Rank::Neun)       => "Nine",
+            (Locale::English, Rank::Acht)       => "Eight",
+            (Locale::English, Rank::Sieben)     => "Seven",
+            (Locale::Bavarian, Rank::Ass)       => "Sau",
+            (Locale::Bavarian, Rank::Zehn)      => "Zehner",
+            (Locale::Bavarian, Rank::Koenig)    => "Kini",
+            (Locale::Bavarian, Rank::Neun)      => "Neuner",
+            (Locale::Bavarian, Rank::Acht)      => "Achter",
+            (Locale::Bavarian, Rank::Sieben)    => "Siebener",
+            (_, Rank::Ober)                     => "Ober",
+            (_, Rank::Unter)                    => "Unter",
+        }
+    }
+
+    /// The card's name, e.g. "Eichel Ober", "Ober of Acorns" or, for the
+    /// Sauen that can be called and the Eichel-Ober in Bavarian, their
+    /// nicknames ("die Alte", "der Alte").
+    pub fn card(self, card: Card) -> String {
+        match self {
+            Locale::German => format!("{} {}", self.suit(card.suit), self.rank(card.rank)),
+            Locale::English => format!("{} of {}", self.rank(card.rank), self.suit(card.suit)),
+            Locale::Bavarian => match (card.suit, card.rank) {
+                (suit, Rank::Ass) if let Some(name) = sau_name(suit) => format!("die {}", name),
+                (Suit::Eichel, Rank::Ober) => "der Alte".to_string(),
+                (suit, rank) => format!("{} {}", self.suit(suit), self.rank(rank)),
+            },
+        }
+    }
+
+    pub fn gamemode(self, mode: &Gamemode) -> String {
+        match self {
+            Locale::German => mode.to_string(),
+            Locale::English => match mode {
+                Gamemode::Sauspiel(suit)    => format!("Call on the Ace of {}", self.suit(*suit)),
+                Gamemode::Solo(suit)        => format!("{} Solo", self.suit(*suit)),
+                Gamemode::Wenz(None)        => "Wenz".to_string(),
+                Gamemode::Wenz(Some(suit))  => format!("Wenz in {}", self.suit(*suit)),
+                Gamemode::Geier(None)       => "Geier".to_string(),
+                Gamemode::Geier(Some(suit)) => format!("Geier in {}", self.suit(*suit)),
+                Gamemode::Bettel            => "Bettel".to_string(),
+                Gamemode::Ramsch            => "Ramsch".to_string(),
+            },
+            Locale::Bavarian => match mode {
+                Gamemode::Sauspiel(suit)    => format!("Auf die {}", sau_name(*suit).unwrap_or(self.suit(*suit))),
+                Gamemode::Solo(suit)        => format!("{}-Solo", self.suit(*suit)),
+                Gamemode::Wenz(Some(suit))  => format!("Farbwenz {}", self.suit(*suit)),
+                Gamemode::Geier(Some(suit)) => format!("Farbgeier {}", self.suit(*suit)),
+                _                           => mode.to_string(),
+            },
+        }
+    }
+
+    /// The announcement's name; Tout and Sie keep their names in every
+    /// locale, e.g. "Hearts Solo Tout".
+    pub fn announcement(self, announcement: &Announcement) -> String {
+        match announcement {
+            Announcement::Game(mode)    => self.gamemode(mode),
+            Announcement::Tout(mode)    => format!("{} Tout", self.gamemode(mode)),
+            Announcement::Sie(mode)     => format!("{} Sie", self.gamemode(mode)),
+        }
+    }
+
+    /// Reads a card named by [`Locale::card`]; short codes such as "EO" and
+    /// the names `Card::from_str` knows are understood in every locale.
+    pub fn parse_card(self, s: &str) -> Result<Card, ParseCardError> {
+        let cards = Suit::iter().flat_map(|suit| Rank::iter().map(move |rank| Card { suit, rank }));
+        find_named(cards.collect(), |&card| self.card(card), s).map_or_else(|| s.parse(), Ok)
+    }
+
+    /// Reads a game mode named by [`Locale::gamemode`].
+    pub fn parse_gamemode(self, s: &str) -> Result<Gamemode, ParseGamemodeError> {
+        find_named(gamemodes().collect(), |mode| self.gamemode(mode), s).ok_or_else(|| ParseGamemodeError::new(s))
+    }
+
+    /// Reads an announcement named by [`Locale::announcement`].
+    pub fn parse_announcement(self, s: &str) -> Result<Announcement, ParseGamemodeError> {
+        let announcements = gamemodes()
+            .flat_map(|mode| [Announcement::Game(mode), Announcement::Tout(mode), Announcement::Sie(mode)]);
+        find_named(announcements.collect(), |announcement| self.announcement(announcement), s)
+            .ok_or_else(|| ParseGamemodeError::new(s))
+    }
+}
+
+// Every game mode there is; a Sauspiel never calls the Herz-Sau
+fn gamemodes() -> impl Iterator<Item = Gamemode> {
+    Suit::iter()
+        .flat_map(|suit| {
+            [Gamemode::Sauspiel(suit), Gamemode::Solo(suit), Gamemode::Wenz(Some(suit)), Gamemode::Geier(Some(suit))]
+        })
+        .filter(|&mode| mode != Gamemode::Sauspiel(Suit::Herz))
+        .chain([Gamemode::Wenz(None), Gamemode::Geier(None), Gamemode::Bettel, Gamemode::Ramsch])
+}
+
+// The item named `input`, ignoring case and spacing. The article only
+// counts where it tells two names apart: "der Alte" is the Eichel-Ober, but
+// "Alte" alone is the first item called that, the Eichel-Sau.
+fn find_named<T>(items: Vec<T>, name: impl Fn(&T) -> String, input: &str) -> Option<T> {
+    let input = words(input);
+    let names: Vec<Vec<String>> = items.iter().map(|item| words(&name(item))).collect();
+    let found = names
+        .iter()
+        .position(|n| *n == input)
+        .or_else(|| names.iter().position(|n| without_article(n) == without_article(&input)))?;
+    items.into_iter().nth(found)
+}
+
+fn words(s: &str) -> Vec<String> {
+    s.to_lowercase().split_whitespace().map(str::to_string).collect()
+}
+
+fn without_article(words: &[String]) -> &[String] {
+    match words.split_first() {
+        Some((article, rest)) if !rest.is_empty() && ["der", "die", "the"].contains(&article.as_str()) => rest,
+        _ => words,
+    }
+}
+
+#[cfg(test)]
+mod tests;
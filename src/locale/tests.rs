@@ -0,0 +1,65 @@
+use super::*;
+
+const LOCALES: [Locale; 3] = [Locale::German, Locale::English, Locale::Bavarian];
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+#[test]
+fn names_cards_per_locale() {
+    let eo = card(Suit::Eichel, Rank::Ober);
+    assert_eq!(Locale::German.card(eo), eo.to_string());
+    assert_eq!(Locale::English.card(eo), "Ober of Acorns");
+    assert_eq!(Locale::Bavarian.card(eo), "der Alte");
+    assert_eq!(Locale::Bavarian.card(card(Suit::Gras, Rank::Ass)), "die Blaue");
+    assert_eq!(Locale::Bavarian.card(card(Suit::Schell, Rank::Koenig)), "Schellen Kini");
+    assert_eq!(Locale::English.suit(Suit::Schell), "Bells");
+}
+
+#[test]
+fn names_games_per_locale() {
+    let sauspiel = Gamemode::Sauspiel(Suit::Eichel);
+    assert_eq!(Locale::German.gamemode(&sauspiel), sauspiel.to_string());
+    assert_eq!(Locale::English.gamemode(&sauspiel), "Call on the Ace of Acorns");
+    assert_eq!(Locale::Bavarian.gamemode(&sauspiel), "Auf die Alte");
+
+    let tout = Announcement::Tout(Gamemode::Solo(Suit::Herz));
+    assert_eq!(Locale::German.announcement(&tout), tout.to_string());
+    assert_eq!(Locale::English.announcement(&tout), "Hearts Solo Tout");
+    assert_eq!(Locale::Bavarian.announcement(&Announcement::Sie(Gamemode::Wenz(None))), "Wenz Sie");
+}
+
+#[test]
+fn every_name_parses_back() {
+    for locale in LOCALES {
+        for suit in Suit::iter() {
+            for rank in Rank::iter() {
+                let c = card(suit, rank);
+                assert_eq!(locale.parse_card(&locale.card(c)), Ok(c), "{:?} {}", locale, c);
+            }
+        }
+        for mode in gamemodes() {
+            assert_eq!(locale.parse_gamemode(&locale.gamemode(&mode)), Ok(mode), "{:?} {:?}", locale, mode);
+            let sie = Announcement::Sie(mode);
+            assert_eq!(locale.parse_announcement(&locale.announcement(&sie)), Ok(sie));
+        }
+    }
+}
+
+#[test]
+fn parsing_is_forgiving() {
+    let alte = card(Suit::Eichel, Rank::Ass);
+    assert_eq!(Locale::Bavarian.parse_card("  ALTE "), Ok(alte));
+    assert_eq!(Locale::Bavarian.parse_card("EA"), Ok(alte));
+    assert_eq!(Locale::English.parse_card("the ace of  acorns"), Ok(alte));
+    assert_eq!(Locale::English.parse_card("Eichel Ass"), Ok(alte));
+    assert!(Locale::English.parse_card("Ace of Spades").is_err());
+
+    assert_eq!(Locale::English.parse_gamemode("bells solo"), Ok(Gamemode::Solo(Suit::Schell)));
+    assert!(Locale::English.parse_gamemode("Herz-Solo").is_err());
+    assert_eq!(
+        Locale::Bavarian.parse_announcement("oachl-solo tout"),
+        Ok(Announcement::Tout(Gamemode::Solo(Suit::Eichel)))
+    );
+}
@@ -0,0 +1,54 @@
+use std::future::{Future, ready};
+use std::pin::Pin;
+
+use crate::deck::Card;
+use crate::game::{Call, PlayerView};
+use crate::gamemode::Announcement;
+use crate::player::{Player, PlayerError};
+
+/// A decision that may take a while, such as a move sent over the network.
+pub type PlayerFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, PlayerError>> + Send + 'a>>;
+
+/// The [`Player`] decisions as futures, for seats that answer from
+/// elsewhere.
+///
+/// The futures do not depend on any particular async runtime. Views and
+/// options are passed by value so they can be sent on; a future that is
+/// dropped before it completes (because the move timed out) must leave the
+/// player ready for the next decision. A player that lost its connection
+/// should answer [`PlayerError::Disconnected`].
+pub trait AsyncPlayer: Send {
+    fn klopfen(&mut self, _view: PlayerView) -> PlayerFuture<'_, bool> {
+        Box::pin(ready(Ok(false)))
+    }
+
+    fn bid(&mut self, view: PlayerView, options: Vec<Announcement>) -> PlayerFuture<'_, Option<Announcement>>;
+
+    fn double(&mut self, _view: PlayerView, _call: Call) -> PlayerFuture<'_, bool> {
+        Box::pin(ready(Ok(false)))
+    }
+
+    fn play_card(&mut self, view: PlayerView, legal: Vec<Card>) -> PlayerFuture<'_, Card>;
+}
+
+/// Seats a synchronous [`Player`] at an asynchronous table; every decision
+/// is made on the spot.
+pub struct Blocking<P>(pub P);
+
+impl<P: Player + Send> AsyncPlayer for Blocking<P> {
+    fn klopfen(&mut self, view: PlayerView) -> PlayerFuture<'_, bool> {
+        Box::pin(ready(self.0.klopfen(&view)))
+    }
+
+    fn bid(&mut self, view: PlayerView, options: Vec<Announcement>) -> PlayerFuture<'_, Option<Announcement>> {
+        Box::pin(ready(self.0.bid(&view, &options)))
+    }
+
+    fn double(&mut self, view: PlayerView, call: Call) -> PlayerFuture<'_, bool> {
+        Box::pin(ready(self.0.double(&view, call)))
+    }
+
+    fn play_card(&mut self, view: PlayerView, legal: Vec<Card>) -> PlayerFuture<'_, Card> {
+        Box::pin(ready(self.0.play_card(&view, &legal)))
+    }
+}
@@ -0,0 +1,252 @@
+//! Rule-of-thumb decisions used by [`NpcPlayer`](crate::player::NpcPlayer).
+
+use crate::deck::{Card, Rank};
+use crate::game::{Call, PlayerView, Seat};
+use crate::gamemode::{Announcement, GameRules, Gamemode};
+
+/// Knock with at least three Ober or Unter among the first four cards.
+pub(crate) fn klopfen(view: &PlayerView) -> bool {
+    view.hand().iter().filter(|c| matches!(c.rank, Rank::Ober | Rank::Unter)).count() >= 3
+}
+
+/// Announces the highest game the hand is strong enough for.
+///
+/// Solo games are judged by trump count, Laufende and side Asse; a Sauspiel
+/// calls the suit the hand holds fewest cards of.
+pub(crate) fn bid(view: &PlayerView, options: &[Announcement]) -> Option<Announcement> {
+    let hand = view.hand();
+
+    if let Some(sie) = options.iter().find(|a| matches!(a, Announcement::Sie(_))) {
+        return Some(*sie);
+    }
+
+    let strong_enough = |a: &Announcement| match a {
+        Announcement::Game(mode @ (Gamemode::Solo(_) | Gamemode::Wenz(_) | Gamemode::Geier(_))) => {
+            let strength = HandStrength::of(hand, mode);
+            match mode {
+                Gamemode::Solo(_) => {
+                    strength.trumps >= 7
+                        || (strength.trumps >= 6 && strength.laufende >= 1)
+                        || (strength.trumps >= 5 && strength.laufende >= 2 && strength.side_asse >= 1)
+                }
+                Gamemode::Wenz(None) | Gamemode::Geier(None) => {
+                    strength.laufende >= 2 && strength.trumps + strength.side_asse >= 5
+                        || strength.trumps >= 3 && strength.side_asse >= 3
+                }
+                _ => strength.trumps >= 6 && strength.laufende >= 1,
+            }
+        }
+        Announcement::Game(mode @ Gamemode::Sauspiel(_)) => {
+            let strength = HandStrength::of(hand, mode);
+            strength.trumps >= 5 || (strength.trumps >= 4 && strength.laufende >= 1)
+        }
+        _ => false,
+    };
+
+    let best_solo = options
+        .iter()
+        .filter(|a| a.gamemode().is_solo() && strong_enough(a))
+        .max_by_key(|a| (a.precedence(), HandStrength::of(hand, a.gamemode()).trumps));
+    if best_solo.is_some() {
+        return best_solo.copied();
+    }
+
+    options
+        .iter()
+        .filter(|a| matches!(a.gamemode(), Gamemode::Sauspiel(_)) && strong_enough(a))
+        .min_by_key(|a| {
+            let mode = a.gamemode();
+            let called = mode.called_card().map(|c| c.suit);
+            hand.iter().filter(|c| Some(c.suit) == called && !mode.is_trump(c)).count()
+        })
+        .copied()
+}
+
+/// Kontra with a strong hand against the game. Re with a very strong hand
+/// as declarer, or as partner with a hand that carries its half of the game.
+pub(crate) fn double(view: &PlayerView, call: Call) -> bool {
+    let Some(rules) = view.rules() else {
+        return false;
+    };
+    let hand = view.hand();
+    let trumps = hand.iter().filter(|c| rules.is_trump(c)).count();
+    let top = rules.trump_order().into_iter().take(4).filter(|c| hand.contains(c)).count();
+    let side_asse = hand.iter().filter(|c| c.rank == Rank::Ass && !rules.is_trump(c)).count();
+    match call {
+        Call::Re if view.declarer() == Some(view.seat()) => trumps >= 7 && top >= 2,
+        // The partner holds the called Sau and judges their own share
+        Call::Re => (trumps >= 4 && top >= 1) || (trumps >= 3 && side_asse >= 2),
+        _ => trumps >= 5 && top >= 2,
+    }
+}
+
+/// Picks a card following common Schafkopf advice.
+///
+/// The declaring side pulls trumps, the other side searches the called Sau,
+/// points go to a partner that takes the trick, a Sau is kept back when the
+/// other side leads its suit, and a lost trick gets the cheapest card.
+pub(crate) fn play_card(view: &PlayerView, legal: &[Card]) -> Card {
+    let Some(rules) = view.rules() else {
+        return legal[0];
+    };
+    if legal.len() == 1 {
+        return legal[0];
+    }
+
+    // Ramsch, and a declarer who wins by taking nothing (Bettel)
+    let avoid_tricks = !rules.has_declarer() || (view.declarer() == Some(view.seat()) && rules.declarer_wins(0, 0));
+    let trick = view.current_trick();
+
+    if avoid_tricks {
+        return dodge(rules, trick.cards(), legal);
+    }
+    if trick.cards().is_empty() {
+        return lead(view, rules, legal);
+    }
+
+    let led = trick.cards()[0];
+    let winning_index = rules.winning_index(trick.cards());
+    let winner = trick.seat_of(winning_index);
+    let winning_card = trick.cards()[winning_index];
+    let last_to_play = trick.cards().len() == 3;
+
+    if is_teammate(view, winner) == Some(true) {
+        let safe = last_to_play
+            || !view.unseen_cards().iter().any(|c| rules.card_strength(c, &led) > rules.card_strength(&winning_card, &led));
+        if safe {
+            return schmieren(rules, legal);
+        }
+        return cheapest(rules, legal, &led);
+    }
+
+    let beats = |c: &Card| rules.card_strength(c, &led) > rules.card_strength(&winning_card, &led);
+    let winners: Vec<Card> = legal.iter().copied().filter(beats).collect();
+    let points_in_trick: u8 = trick.cards().iter().map(|c| rules.card_points(c)).sum();
+
+    if last_to_play && !winners.is_empty() {
+        // Take the trick with the most valuable card that still wins it
+        return *winners
+            .iter()
+            .max_by_key(|c| (!rules.is_trump(c), rules.card_points(c), std::cmp::Reverse(rules.card_strength(c, &led))))
+            .unwrap();
+    }
+
+    // Keep the Sau back when the other side leads its suit and a trump can still come
+    let exposed_sau = |c: &Card| c.rank == Rank::Ass && !rules.is_trump(c) && !last_to_play;
+    let worth_taking = points_in_trick >= 10 || rules.is_trump(&led);
+    if let Some(card) = winners
+        .iter()
+        .copied()
+        .filter(|c| !exposed_sau(c) && (worth_taking || !rules.is_trump(c)))
+        .min_by_key(|c| rules.card_strength(c, &led))
+    {
+        return card;
+    }
+
+    cheapest(rules, legal, &led)
+}
+
+// Leading a trick
+fn lead(view: &PlayerView, rules: &dyn GameRules, legal: &[Card]) -> Card {
+    let me = view.seat();
+    let on_declarer_side = view.declarer() == Some(me) || view.partner() == Some(me);
+    let trumps: Vec<Card> = legal.iter().copied().filter(|c| rules.is_trump(c)).collect();
+    let trumps_out = view.unseen_cards().iter().any(|c| rules.is_trump(c));
+
+    if on_declarer_side && trumps_out
+        && let Some(&highest) = trumps.iter().max_by_key(|c| rules.card_strength(c, c))
+    {
+        return highest;
+    }
+
+    if !on_declarer_side && let Some(sau) = rules.called_card() {
+        let sau_out = !view.hand().contains(&sau) && !view.played_cards().any(|(_, c)| c == sau);
+        if sau_out
+            && let Some(&low) = legal
+                .iter()
+                .filter(|c| rules.follows(c, &sau))
+                .min_by_key(|c| rules.card_strength(c, c))
+        {
+            return low;
+        }
+    }
+
+    // A side Sau whose suit has not been played yet has a good chance to stand
+    let fresh_sau = legal.iter().copied().find(|c| {
+        c.rank == Rank::Ass
+            && !rules.is_trump(c)
+            && !view.played_cards().any(|(_, p)| !rules.is_trump(&p) && p.suit == c.suit)
+    });
+    if let Some(sau) = fresh_sau {
+        return sau;
+    }
+
+    let side_cards: Vec<Card> = legal.iter().copied().filter(|c| !rules.is_trump(c)).collect();
+    let pool = if side_cards.is_empty() { legal } else { &side_cards };
+    cheapest(rules, pool, &pool[0])
+}
+
+// Highest-value card for a partner who takes the trick; trumps only if nothing else
+fn schmieren(rules: &dyn GameRules, legal: &[Card]) -> Card {
+    *legal
+        .iter()
+        .max_by_key(|c| (rules.card_points(c), !rules.is_trump(c), std::cmp::Reverse(rules.card_strength(c, c))))
+        .unwrap()
+}
+
+// Fewest points, then weakest
+fn cheapest(rules: &dyn GameRules, legal: &[Card], led: &Card) -> Card {
+    *legal
+        .iter()
+        .min_by_key(|c| (rules.card_points(c), rules.is_trump(c), rules.card_strength(c, led)))
+        .unwrap()
+}
+
+// Ramsch and Bettel: the strongest card that does not take the trick
+fn dodge(rules: &dyn GameRules, trick: &[Card], legal: &[Card]) -> Card {
+    let Some(led) = trick.first() else {
+        return *legal.iter().min_by_key(|c| rules.card_strength(c, c)).unwrap();
+    };
+    let best = trick.iter().map(|c| rules.card_strength(c, led)).max().unwrap_or(0);
+    legal
+        .iter()
+        .copied()
+        .filter(|c| rules.card_strength(c, led) < best)
+        .max_by_key(|c| (rules.card_strength(c, led), rules.card_points(c)))
+        .unwrap_or_else(|| cheapest(rules, legal, led))
+}
+
+/// Whether `seat` is known to play on the viewer's side.
+///
+/// Unknown in a Sauspiel as long as the partner has not shown up.
+fn is_teammate(view: &PlayerView, seat: Seat) -> Option<bool> {
+    view.declarer()?;
+    if seat == view.seat() {
+        return Some(true);
+    }
+    let i_declare = view.is_known_declarer_side(view.seat());
+    let partner_known = view.partner().is_some() || view.rules().is_some_and(|r| r.called_card().is_none());
+
+    if view.is_known_declarer_side(seat) {
+        Some(i_declare)
+    } else if partner_known {
+        Some(!i_declare)
+    } else {
+        None
+    }
+}
+
+struct HandStrength {
+    trumps: usize,
+    laufende: usize,
+    side_asse: usize,
+}
+
+impl HandStrength {
+    fn of(hand: &[Card], mode: &Gamemode) -> Self {
+        let trumps = hand.iter().filter(|c| mode.is_trump(c)).count();
+        let laufende = mode.trumps().iter().take_while(|c| hand.contains(c)).count();
+        let side_asse = hand.iter().filter(|c| c.rank == Rank::Ass && !mode.is_trump(c)).count();
+        Self { trumps, laufende, side_asse }
+    }
+}
@@ -0,0 +1,201 @@
+use std::io::{self, BufRead, Read, Stdout, Write};
+
+use crate::deck::Card;
+use crate::game::{Call, PlayerView};
+use crate::gamemode::Announcement;
+use crate::player::{Player, PlayerBase, PlayerBaseAccess, PlayerError};
+
+const HELP: &str = "Enter a number or a card such as \"eo\", \"g10\" or \"gras sau\". \
+                    Commands: hand, last (the last trick), help.";
+
+/// Standard input, read one line at a time.
+///
+/// Nothing is buffered beyond the line asked for, so several players at the
+/// same terminal can share it.
+#[derive(Default)]
+pub struct StdinLines {
+    line: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for StdinLines {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for StdinLines {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            io::stdin().lock().read_until(b'\n', &mut self.line)?;
+        }
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.pos += amount;
+    }
+}
+
+/// A person at a terminal, or anything else that talks through a
+/// [`BufRead`]/[`Write`] pair.
+///
+/// Invalid input is answered with a hint and asked for again; running out
+/// of input ends the round with [`PlayerError::Io`].
+pub struct HumanPlayer<R = StdinLines, W = Stdout> {
+    pub base: PlayerBase,
+    input: R,
+    output: W,
+}
+
+impl HumanPlayer {
+    /// A player on standard input and output.
+    pub fn new(id: u32, name: impl Into<String>) -> Self {
+        Self::with_io(id, name, StdinLines::default(), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> HumanPlayer<R, W> {
+    pub fn with_io(id: u32, name: impl Into<String>, input: R, output: W) -> Self {
+        Self { base: PlayerBase::new(id, name), input, output }
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    fn read_line(&mut self, prompt: &str) -> Result<String, PlayerError> {
+        write!(self.output, "{}", prompt)?;
+        self.output.flush()?;
+        let mut input = String::new();
+        if self.input.read_line(&mut input)? == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(input.trim().to_lowercase())
+    }
+
+    fn ask_yes_no(&mut self, question: &str) -> Result<bool, PlayerError> {
+        loop {
+            match self.read_line(&format!("{} [y/N] ", question))?.as_str() {
+                "" | "n" | "no" | "nein" => return Ok(false),
+                "y" | "yes" | "j" | "ja" => return Ok(true),
+                _ => writeln!(self.output, "Please answer y or n.")?,
+            }
+        }
+    }
+
+    fn show_cards(&mut self, title: &str, cards: &[Card]) -> io::Result<()> {
+        writeln!(self.output, "{}:", title)?;
+        for c in cards {
+            writeln!(self.output, "  {}", c)?;
+        }
+        Ok(())
+    }
+
+    // Handles the commands available at every prompt, false for other input
+    fn command(&mut self, input: &str, view: &PlayerView) -> io::Result<bool> {
+        match input {
+            "?" | "help" => writeln!(self.output, "{}", HELP)?,
+            "hand" => self.show_cards("Your hand", view.hand())?,
+            "last" => match view.tricks().last() {
+                Some(trick) => {
+                    writeln!(self.output, "Last trick:")?;
+                    for (seat, c) in trick.plays() {
+                        writeln!(self.output, "  seat {}: {}", seat, c)?;
+                    }
+                }
+                None => writeln!(self.output, "No trick has been played yet.")?,
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+impl<R, W> PlayerBaseAccess for HumanPlayer<R, W> {
+    fn base(&self) -> &PlayerBase {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut PlayerBase {
+        &mut self.base
+    }
+}
+
+impl<R: BufRead, W: Write> Player for HumanPlayer<R, W> {
+    fn klopfen(&mut self, view: &PlayerView) -> Result<bool, PlayerError> {
+        let title = format!("{}'s first cards", self.name());
+        self.show_cards(&title, view.hand())?;
+        self.ask_yes_no("Klopfen?")
+    }
+
+    fn bid(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        let title = format!("{}'s hand", self.name());
+        self.show_cards(&title, view.hand())?;
+        writeln!(self.output, "  0: weiter")?;
+        for (i, a) in options.iter().enumerate() {
+            writeln!(self.output, "  {}: {}", i + 1, a)?;
+        }
+
+        loop {
+            let input = self.read_line("Select game to announce: ")?;
+            if self.command(&input, view)? {
+                continue;
+            }
+            if input == "weiter" || input == "0" {
+                return Ok(None);
+            }
+            let chosen = match input.parse::<usize>() {
+                Ok(i) => i.checked_sub(1).and_then(|i| options.get(i)).copied(),
+                Err(_) => input.parse::<Announcement>().ok().filter(|a| options.contains(a)),
+            };
+            match chosen {
+                Some(a) => return Ok(Some(a)),
+                None => writeln!(self.output, "\"{}\" is not one of the options.", input)?,
+            }
+        }
+    }
+
+    fn double(&mut self, _view: &PlayerView, call: Call) -> Result<bool, PlayerError> {
+        self.ask_yes_no(&format!("{:?}?", call))
+    }
+
+    fn play_card(&mut self, view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        if legal.is_empty() {
+            return Err(PlayerError::NoCards);
+        }
+
+        if !view.current_trick().cards().is_empty() {
+            writeln!(self.output, "Trick so far:")?;
+            for (seat, c) in view.current_trick().plays() {
+                writeln!(self.output, "  seat {}: {}", seat, c)?;
+            }
+        }
+        writeln!(self.output, "{}'s playable cards:", self.base.name)?;
+        for (i, c) in legal.iter().enumerate() {
+            writeln!(self.output, "  {}: {}", i, c)?;
+        }
+
+        loop {
+            let input = self.read_line("Select card to play: ")?;
+            if self.command(&input, view)? {
+                continue;
+            }
+            let card = match input.parse::<usize>() {
+                Ok(i) => legal.get(i).copied(),
+                Err(_) => input.parse::<Card>().ok(),
+            };
+            match card {
+                Some(card) if legal.contains(&card) => return Ok(card),
+                Some(card) if view.hand().contains(&card) => writeln!(self.output, "{} may not be played now.", card)?,
+                Some(card) => writeln!(self.output, "You do not hold {}.", card)?,
+                None => writeln!(self.output, "Unknown card \"{}\". {}", input, HELP)?,
+            }
+        }
+    }
+}
@@ -1,22 +1,80 @@
-use std::io::{self, Write};
+use std::io;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
 use crate::deck::Card;
+use crate::game::{Call, PlayerView, Seat};
+use crate::gamemode::Announcement;
 use std::fmt;
 
+mod asynchronous;
+pub use asynchronous::{AsyncPlayer, Blocking, PlayerFuture};
+
+mod heuristic;
+
+mod human;
+pub use human::{HumanPlayer, StdinLines};
+
+mod pimc;
+pub use pimc::{PimcConfig, PimcPlayer};
+
+mod replay;
+pub use replay::ReplayPlayer;
+
 #[derive(Debug)]
 pub enum PlayerError {
     NoCards,
+    /// The player chose a card that is not among the legal ones.
+    IllegalCard(Card),
+    /// The player announced a game that was not offered.
+    IllegalBid(Announcement),
+    /// Reading the player's input or writing to it failed.
+    Io(io::Error),
+    /// The seat did not decide within the time limit.
+    Timeout(Seat),
+    /// The player can no longer be reached.
+    Disconnected,
+    /// A [`ReplayPlayer`] was asked for more than its recording holds.
+    ReplayExhausted,
+    /// A [`ReplayPlayer`] was asked for something else than the recorded
+    /// call it has next.
+    ReplayMismatch(Call),
+    /// A [`ReplayPlayer`] finished with this many recorded actions unused.
+    ReplayLeftover(usize),
 }
 
 impl fmt::Display for PlayerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             PlayerError::NoCards => write!(f, "no cards available to play"),
+            PlayerError::IllegalCard(card) => write!(f, "{} may not be played now", card),
+            PlayerError::IllegalBid(bid) => write!(f, "{} may not be announced now", bid),
+            PlayerError::Io(err) => write!(f, "player input failed: {}", err),
+            PlayerError::Timeout(seat) => write!(f, "seat {} did not move in time", seat),
+            PlayerError::Disconnected => write!(f, "the player has disconnected"),
+            PlayerError::ReplayExhausted => write!(f, "the recording has no more moves for this seat"),
+            PlayerError::ReplayMismatch(call) => write!(f, "the recording has {:?} next, which was not asked for", call),
+            PlayerError::ReplayLeftover(n) => write!(f, "{} recorded moves were never replayed", n),
         }
     }
 }
 
-impl std::error::Error for PlayerError {}
+impl std::error::Error for PlayerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PlayerError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
+impl From<io::Error> for PlayerError {
+    fn from(err: io::Error) -> Self {
+        PlayerError::Io(err)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerBase {
     pub id: u32,
     pub name: String,
@@ -38,10 +96,6 @@ impl PlayerBase {
     pub fn set_name(&mut self, name: impl Into<String>) {
         self.name = name.into();
     }
-
-    pub fn play_card(&mut self, hand: &mut Vec<Card>) -> Result<Card, PlayerError> {
-        hand.pop().ok_or(PlayerError::NoCards)
-    }
 }
 
 pub trait PlayerBaseAccess {
@@ -61,115 +115,112 @@ pub trait PlayerBaseAccess {
     }
 }
 
-pub trait ExternalPlayer: PlayerBaseAccess {
-    fn play_card(&mut self, hand: &mut Vec<Card>) -> Result<Card, PlayerError> {
-        self.base_mut().play_card(hand)
+/// The decisions a seat makes during a round.
+///
+/// Every callback gets a [`PlayerView`] of what the seat may know and the
+/// options it may choose from; the table driver in [`crate::table`] rejects
+/// anything else. Humans, bots and remote clients all plug in through this
+/// trait.
+pub trait Player {
+    /// Whether to knock after seeing the first four cards.
+    fn klopfen(&mut self, _view: &PlayerView) -> Result<bool, PlayerError> {
+        Ok(false)
     }
-}
 
-pub trait InternalPlayer: PlayerBaseAccess {
-    fn play_card_from_hand(&mut self) -> Result<Card, PlayerError>;
-    fn receive_card(&mut self, card: Card);
-    fn set_hand(&mut self, hand: Vec<Card>);
-    fn hand(&self) -> &Vec<Card>;
+    /// Announces one of `options` or passes with `None` ("weiter").
+    fn bid(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError>;
+
+    /// Whether to say `call` (Kontra or Re) on the announced game.
+    fn double(&mut self, _view: &PlayerView, _call: Call) -> Result<bool, PlayerError> {
+        Ok(false)
+    }
+
+    /// Picks one of the `legal` cards.
+    fn play_card(&mut self, view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError>;
 }
 
-pub struct HumanPlayer {
+/// A computer opponent playing by common rules of thumb.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NpcPlayer {
     pub base: PlayerBase,
-    pub hand: Vec<Card>,
 }
 
-impl HumanPlayer {
+impl NpcPlayer {
     pub fn new(id: u32, name: impl Into<String>) -> Self {
-        Self {
-            base: PlayerBase::new(id, name),
-            hand: Vec::with_capacity(8),
-        }
+        Self { base: PlayerBase::new(id, name) }
     }
 }
 
-impl PlayerBaseAccess for HumanPlayer {
-    fn base(&self) -> &PlayerBase {
-        &self.base
-    }
-    fn base_mut(&mut self) -> &mut PlayerBase {
-        &mut self.base
-    }
+impl PlayerBaseAccess for NpcPlayer {
+    fn base(&self) -> &PlayerBase { &self.base }
+    fn base_mut(&mut self) -> &mut PlayerBase { &mut self.base }
 }
 
-impl InternalPlayer for HumanPlayer {
-    fn play_card_from_hand(&mut self) -> Result<Card, PlayerError> {
-        if self.hand.is_empty() {
-            return Err(PlayerError::NoCards);
-        }
-
-        println!("{}'s hand:", self.name());
-        for (i, c) in self.hand.iter().enumerate() {
-            println!("  {}: {}", i, c);
-        }
-        print!("Select card index to play: ");
-        let _ = io::stdout().flush();
-
-        let mut input = String::new();
-        if io::stdin().read_line(&mut input).is_ok() {
-            if let Ok(idx) = input.trim().parse::<usize>() {
-                if idx < self.hand.len() {
-                    return Ok(self.hand.remove(idx));
-                }
-            }
-        }
-
-        // fallback: pop last
-        self.hand.pop().ok_or(PlayerError::NoCards)
+impl Player for NpcPlayer {
+    fn klopfen(&mut self, view: &PlayerView) -> Result<bool, PlayerError> {
+        Ok(heuristic::klopfen(view))
     }
 
-    fn receive_card(&mut self, card: Card) {
-        self.hand.push(card);
+    fn bid(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        Ok(heuristic::bid(view, options))
     }
 
-    fn set_hand(&mut self, hand: Vec<Card>) {
-        self.hand = hand;
+    fn double(&mut self, view: &PlayerView, call: Call) -> Result<bool, PlayerError> {
+        Ok(heuristic::double(view, call))
     }
 
-    fn hand(&self) -> &Vec<Card> {
-        &self.hand
+    fn play_card(&mut self, view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        if legal.is_empty() {
+            return Err(PlayerError::NoCards);
+        }
+        Ok(heuristic::play_card(view, legal))
     }
 }
 
-pub struct NpcPlayer {
+/// A baseline opponent choosing uniformly among everything it may do.
+///
+/// Klopfen, Kontra and Re are said with even odds, passing is one more
+/// option in the auction. Seeded, it plays the same way every time, which
+/// makes it useful for fuzzing the engine and as a reference for other bots.
+pub struct RandomPlayer {
     pub base: PlayerBase,
-    pub hand: Vec<Card>,
+    rng: StdRng,
 }
 
-impl NpcPlayer {
+impl RandomPlayer {
+    /// A random player seeded from the operating system.
     pub fn new(id: u32, name: impl Into<String>) -> Self {
-        Self { base: PlayerBase::new(id, name), hand: Vec::with_capacity(8) }
+        Self { base: PlayerBase::new(id, name), rng: StdRng::from_os_rng() }
+    }
+
+    pub fn with_seed(id: u32, name: impl Into<String>, seed: u64) -> Self {
+        Self { base: PlayerBase::new(id, name), rng: StdRng::seed_from_u64(seed) }
     }
 }
 
-impl PlayerBaseAccess for NpcPlayer {
+impl PlayerBaseAccess for RandomPlayer {
     fn base(&self) -> &PlayerBase { &self.base }
     fn base_mut(&mut self) -> &mut PlayerBase { &mut self.base }
 }
 
-impl InternalPlayer for NpcPlayer {
-    fn play_card_from_hand(&mut self) -> Result<Card, PlayerError> {
-        if self.hand.is_empty() {
-            Err(PlayerError::NoCards)
-        } else {
-            Ok(self.hand.remove(0))
-        }
+impl Player for RandomPlayer {
+    fn klopfen(&mut self, _view: &PlayerView) -> Result<bool, PlayerError> {
+        Ok(self.rng.random())
     }
 
-    fn receive_card(&mut self, card: Card) {
-        self.hand.push(card);
+    fn bid(&mut self, _view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        let choice = self.rng.random_range(0..=options.len());
+        Ok(options.get(choice).copied())
     }
 
-    fn set_hand(&mut self, hand: Vec<Card>) {
-        self.hand = hand;
+    fn double(&mut self, _view: &PlayerView, _call: Call) -> Result<bool, PlayerError> {
+        Ok(self.rng.random())
     }
 
-    fn hand(&self) -> &Vec<Card> {
-        &self.hand
+    fn play_card(&mut self, _view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        legal.choose(&mut self.rng).copied().ok_or(PlayerError::NoCards)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,172 @@
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::deck::Card;
+use crate::game::{Call, Game, PlayerView, Seat};
+use crate::gamemode::Announcement;
+use crate::player::{Player, PlayerBase, PlayerBaseAccess, PlayerError, heuristic};
+use crate::tracker::Tracker;
+
+/// Settings for [`PimcPlayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PimcConfig {
+    /// Number of deals sampled per decision.
+    pub samples: usize,
+    /// Stop sampling early once this much time has passed.
+    pub time_budget: Option<Duration>,
+    /// Seed for the sampling; `None` seeds from the operating system.
+    pub seed: Option<u64>,
+}
+
+impl Default for PimcConfig {
+    fn default() -> Self {
+        Self { samples: 50, time_budget: None, seed: None }
+    }
+}
+
+/// A card-play bot using perfect information Monte Carlo (PIMC).
+///
+/// For every decision it samples deals of the unseen cards that agree with
+/// everything the seat has seen, plays each legal card out in every sample
+/// with the [`NpcPlayer`](crate::player::NpcPlayer) heuristics for all
+/// seats, and picks the card with the best average result. Bidding is left
+/// to the heuristics.
+pub struct PimcPlayer {
+    pub base: PlayerBase,
+    pub config: PimcConfig,
+    rng: StdRng,
+}
+
+impl PimcPlayer {
+    pub fn new(id: u32, name: impl Into<String>, config: PimcConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self { base: PlayerBase::new(id, name), config, rng }
+    }
+}
+
+impl PlayerBaseAccess for PimcPlayer {
+    fn base(&self) -> &PlayerBase { &self.base }
+    fn base_mut(&mut self) -> &mut PlayerBase { &mut self.base }
+}
+
+impl Player for PimcPlayer {
+    fn klopfen(&mut self, view: &PlayerView) -> Result<bool, PlayerError> {
+        Ok(heuristic::klopfen(view))
+    }
+
+    fn bid(&mut self, view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        Ok(heuristic::bid(view, options))
+    }
+
+    fn double(&mut self, view: &PlayerView, call: Call) -> Result<bool, PlayerError> {
+        Ok(heuristic::double(view, call))
+    }
+
+    fn play_card(&mut self, view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        match legal {
+            [] => return Err(PlayerError::NoCards),
+            [only] => return Ok(*only),
+            _ => {}
+        }
+
+        let start = Instant::now();
+        let wanted = self.config.samples.max(1);
+        let mut totals = vec![0i64; legal.len()];
+        let mut samples = 0;
+        for _ in 0..wanted * 4 {
+            let out_of_time = self.config.time_budget.is_some_and(|budget| start.elapsed() >= budget);
+            if samples == wanted || (samples > 0 && out_of_time) {
+                break;
+            }
+            let Some(game) = sample_game(view, &mut self.rng) else {
+                continue;
+            };
+            for (total, card) in totals.iter_mut().zip(legal) {
+                let mut rollout = game.clone();
+                if rollout.play(*card).is_ok() {
+                    play_out(&mut rollout);
+                    *total += utility(&rollout, view.seat());
+                }
+            }
+            samples += 1;
+        }
+
+        let best = (0..legal.len()).max_by_key(|&i| totals[i]).unwrap_or(0);
+        Ok(legal[best])
+    }
+}
+
+/// Deals the cards `view` has not seen to the other seats and replays the
+/// game so far on that deal.
+///
+/// No seat gets a card the [`Tracker`] rules out for it. Returns `None` if
+/// no such deal was found within a few attempts.
+pub(crate) fn sample_game(view: &PlayerView, rng: &mut impl Rng) -> Option<Game> {
+    let rules = view.shared_rules()?;
+    let me = view.seat();
+
+    let tracker = Tracker::from_view(view)?;
+    let may_hold = |seat: Seat, card: &Card| tracker.may_hold(seat, card);
+
+    let mut unseen = view.unseen_cards();
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    'attempt: for _ in 0..100 {
+        unseen.shuffle(rng);
+        // Most constrained cards first
+        unseen.sort_by_key(|c| (0..4).filter(|&s| s != me && may_hold(s, c)).count());
+        hands = Default::default();
+        hands[me] = view.hand().to_vec();
+        for card in &unseen {
+            let seats: Vec<Seat> = (0..4)
+                .filter(|&s| s != me && hands[s].len() < view.hand_size(s) && may_hold(s, card))
+                .collect();
+            let Some(&seat) = seats.get(rng.random_range(0..seats.len().max(1))) else {
+                continue 'attempt;
+            };
+            hands[seat].push(*card);
+        }
+        break;
+    }
+    if (0..4).any(|s| hands[s].len() != view.hand_size(s)) {
+        return None;
+    }
+
+    let played: Vec<(Seat, Card)> = view.played_cards().collect();
+    for &(seat, card) in &played {
+        hands[seat].push(card);
+    }
+    let leader = view.tricks().first().unwrap_or(view.current_trick()).leader();
+    let mut game = Game::with_rules(rules, hands, leader, view.declarer());
+    for (_, card) in played {
+        game.play(card).ok()?;
+    }
+    Some(game)
+}
+
+fn play_out(game: &mut Game) {
+    while let Some(seat) = game.current_player() {
+        let legal = game.legal_cards();
+        let card = heuristic::play_card(&game.view(seat), &legal);
+        if game.play(card).is_err() {
+            return;
+        }
+    }
+}
+
+// Winning counts more than any number of points; in Ramsch fewer points are better
+fn utility(game: &Game, seat: Seat) -> i64 {
+    let Some(outcome) = game.outcome() else {
+        return -(game.points(seat) as i64);
+    };
+    let declarer_side = game.is_declarer_side(seat);
+    let points = if declarer_side { outcome.declarer_points } else { 120 - outcome.declarer_points };
+    let won = declarer_side == outcome.declarer_wins;
+    points as i64 + if won { 121 } else { 0 }
+}
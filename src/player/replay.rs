@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use crate::deck::Card;
+use crate::game::{Call, Game, PlayerView, Seat};
+use crate::gamemode::Announcement;
+use crate::player::{Player, PlayerBase, PlayerBaseAccess, PlayerError};
+
+/// Plays back what one seat did in a recorded round.
+///
+/// The recording is replayed strictly in order: every question must be
+/// the one the next recorded action answers. A Klopfen, Kontra or Re that
+/// is not recorded next is answered no. Anything else that does not fit
+/// ends the round:
+/// - a recorded call asked out of turn with [`PlayerError::ReplayMismatch`],
+/// - a recorded bid or card that is not allowed with
+///   [`PlayerError::IllegalBid`] or [`PlayerError::IllegalCard`],
+/// - running out of recorded bids or cards with
+///   [`PlayerError::ReplayExhausted`].
+///
+/// [`ReplayPlayer::finish`] checks afterwards that nothing was left over, so
+/// a replay never silently goes its own way.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayPlayer {
+    pub base: PlayerBase,
+    calls: VecDeque<Call>,
+    cards: VecDeque<Card>,
+}
+
+impl ReplayPlayer {
+    /// Replays `calls` (Klopfen, the bid or Weiter, Kontra and Re) and
+    /// `cards` in the order given.
+    pub fn new(id: u32, name: impl Into<String>, calls: Vec<Call>, cards: Vec<Card>) -> Self {
+        Self { base: PlayerBase::new(id, name), calls: calls.into(), cards: cards.into() }
+    }
+
+    /// Replays what `seat` said and played in `game`.
+    pub fn from_game(id: u32, name: impl Into<String>, game: &Game, seat: Seat) -> Self {
+        let calls = game.calls().iter().filter(|&&(s, _)| s == seat).map(|&(_, call)| call).collect();
+        let cards = game.played_cards().filter(|&(s, _)| s == seat).map(|(_, card)| card).collect();
+        Self::new(id, name, calls, cards)
+    }
+
+    /// Whether every recorded action has been replayed.
+    pub fn is_done(&self) -> bool {
+        self.calls.is_empty() && self.cards.is_empty()
+    }
+
+    /// Fails with [`PlayerError::ReplayLeftover`] unless the whole
+    /// recording has been replayed.
+    pub fn finish(&self) -> Result<(), PlayerError> {
+        match self.calls.len() + self.cards.len() {
+            0 => Ok(()),
+            n => Err(PlayerError::ReplayLeftover(n)),
+        }
+    }
+
+    // Takes the next call if it is `call`. Otherwise the question is
+    // answered no, which is a mismatch if `may_follow` rejects the next call.
+    fn take_if(&mut self, call: Call, may_follow: impl Fn(&Call) -> bool) -> Result<bool, PlayerError> {
+        match self.calls.front() {
+            Some(next) if *next == call => {
+                self.calls.pop_front();
+                Ok(true)
+            }
+            Some(next) if !may_follow(next) => Err(PlayerError::ReplayMismatch(*next)),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl PlayerBaseAccess for ReplayPlayer {
+    fn base(&self) -> &PlayerBase { &self.base }
+    fn base_mut(&mut self) -> &mut PlayerBase { &mut self.base }
+}
+
+impl Player for ReplayPlayer {
+    fn klopfen(&mut self, _view: &PlayerView) -> Result<bool, PlayerError> {
+        if self.calls.is_empty() {
+            return Err(PlayerError::ReplayExhausted);
+        }
+        // Not knocking: the seat's bid comes next
+        self.take_if(Call::Klopfen, |next| matches!(next, Call::Announce(_) | Call::Weiter))
+    }
+
+    fn bid(&mut self, _view: &PlayerView, options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        match self.calls.pop_front() {
+            Some(Call::Announce(bid)) if options.contains(&bid) => Ok(Some(bid)),
+            Some(Call::Announce(bid)) => Err(PlayerError::IllegalBid(bid)),
+            Some(Call::Weiter) => Ok(None),
+            Some(call) => Err(PlayerError::ReplayMismatch(call)),
+            None => Err(PlayerError::ReplayExhausted),
+        }
+    }
+
+    fn double(&mut self, _view: &PlayerView, call: Call) -> Result<bool, PlayerError> {
+        // A seat is only asked for Kontra or for Re, never both
+        self.take_if(call, |_| false)
+    }
+
+    fn play_card(&mut self, _view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        if let Some(&call) = self.calls.front() {
+            return Err(PlayerError::ReplayMismatch(call));
+        }
+        let card = self.cards.pop_front().ok_or(PlayerError::ReplayExhausted)?;
+        if legal.contains(&card) { Ok(card) } else { Err(PlayerError::IllegalCard(card)) }
+    }
+}
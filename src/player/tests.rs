@@ -0,0 +1,290 @@
+use super::*;
+use crate::deck::{Rank, Suit};
+use crate::game::Game;
+use crate::gamemode::Gamemode;
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+fn npc_card(game: &Game) -> Card {
+    let seat = game.current_player().unwrap();
+    NpcPlayer::new(0, "NPC").play_card(&game.view(seat), &game.legal_cards()).unwrap()
+}
+
+#[test]
+fn npc_declarer_leads_highest_trump() {
+    let hands = [
+        vec![card(Suit::Herz, Rank::Ober), card(Suit::Eichel, Rank::Unter), card(Suit::Herz, Rank::Sieben), card(Suit::Gras, Rank::Sieben)],
+        vec![card(Suit::Herz, Rank::Ass), card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Ass), card(Suit::Gras, Rank::Acht)],
+        vec![card(Suit::Schell, Rank::Ass), card(Suit::Schell, Rank::Zehn), card(Suit::Gras, Rank::Zehn), card(Suit::Gras, Rank::Neun)],
+        vec![card(Suit::Eichel, Rank::Ass), card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Koenig), card(Suit::Eichel, Rank::Neun)],
+    ];
+    let game = Game::new(Gamemode::Solo(Suit::Herz), hands, 0, Some(0));
+    assert_eq!(npc_card(&game), card(Suit::Herz, Rank::Ober));
+}
+
+#[test]
+fn npc_searches_called_sau() {
+    let hands = [
+        vec![card(Suit::Gras, Rank::Neun), card(Suit::Eichel, Rank::Ass), card(Suit::Schell, Rank::Sieben), card(Suit::Herz, Rank::Unter)],
+        vec![card(Suit::Herz, Rank::Ober), card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Koenig), card(Suit::Gras, Rank::Acht)],
+        vec![card(Suit::Gras, Rank::Ass), card(Suit::Schell, Rank::Zehn), card(Suit::Gras, Rank::Zehn), card(Suit::Schell, Rank::Neun)],
+        vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Eichel, Rank::Neun), card(Suit::Schell, Rank::Koenig), card(Suit::Gras, Rank::Sieben)],
+    ];
+    let game = Game::new(Gamemode::Sauspiel(Suit::Gras), hands, 0, Some(1));
+    assert_eq!(npc_card(&game), card(Suit::Gras, Rank::Neun));
+}
+
+fn gras_trick_game(last_hand: Vec<Card>, declarer: usize) -> Game {
+    let hands = [
+        vec![card(Suit::Gras, Rank::Acht), card(Suit::Herz, Rank::Acht)],
+        last_hand,
+        vec![card(Suit::Gras, Rank::Ass), card(Suit::Herz, Rank::Neun)],
+        vec![card(Suit::Gras, Rank::Sieben), card(Suit::Herz, Rank::Ober)],
+    ];
+    let mut game = Game::new(Gamemode::Solo(Suit::Herz), hands, 2, Some(declarer));
+    game.play(card(Suit::Gras, Rank::Ass)).unwrap();
+    game.play(card(Suit::Gras, Rank::Sieben)).unwrap();
+    game.play(card(Suit::Gras, Rank::Acht)).unwrap();
+    game
+}
+
+#[test]
+fn npc_schmiert_to_partner() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 3);
+    assert_eq!(npc_card(&game), card(Suit::Eichel, Rank::Zehn));
+}
+
+#[test]
+fn npc_plays_low_into_lost_trick() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 1);
+    assert_eq!(npc_card(&game), card(Suit::Schell, Rank::Sieben));
+}
+
+#[test]
+fn npc_keeps_sau_back_when_other_side_leads() {
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Neun), card(Suit::Herz, Rank::Ober)],
+        vec![card(Suit::Eichel, Rank::Ass), card(Suit::Eichel, Rank::Acht)],
+        vec![card(Suit::Schell, Rank::Ass), card(Suit::Schell, Rank::Zehn)],
+        vec![card(Suit::Gras, Rank::Ass), card(Suit::Gras, Rank::Zehn)],
+    ];
+    let mut game = Game::new(Gamemode::Solo(Suit::Herz), hands, 0, Some(0));
+    game.play(card(Suit::Eichel, Rank::Neun)).unwrap();
+    assert_eq!(npc_card(&game), card(Suit::Eichel, Rank::Acht));
+}
+
+#[test]
+fn npc_bids_by_trump_count_and_laufende() {
+    let mut npc = NpcPlayer::new(0, "NPC");
+    let ruleset = crate::gamemode::Ruleset::default();
+    let bid = |npc: &mut NpcPlayer, hand: &[Card]| {
+        let view = PlayerView::before_game(0, hand, 0, &[]);
+        npc.bid(&view, &crate::gamemode::available_announcements(hand, &ruleset)).unwrap()
+    };
+
+    let solo = [
+        card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Ober), card(Suit::Herz, Rank::Ober),
+        card(Suit::Schell, Rank::Unter), card(Suit::Herz, Rank::Ass), card(Suit::Herz, Rank::Koenig),
+        card(Suit::Herz, Rank::Neun), card(Suit::Gras, Rank::Sieben),
+    ];
+    assert_eq!(bid(&mut npc, &solo), Some(Announcement::Game(Gamemode::Solo(Suit::Herz))));
+
+    let sauspiel = [
+        card(Suit::Eichel, Rank::Ober), card(Suit::Gras, Rank::Unter), card(Suit::Herz, Rank::Zehn),
+        card(Suit::Herz, Rank::Acht), card(Suit::Schell, Rank::Koenig), card(Suit::Eichel, Rank::Neun),
+        card(Suit::Eichel, Rank::Sieben), card(Suit::Gras, Rank::Ass),
+    ];
+    assert_eq!(bid(&mut npc, &sauspiel), Some(Announcement::Game(Gamemode::Sauspiel(Suit::Schell))));
+
+    let weak = [
+        card(Suit::Schell, Rank::Unter), card(Suit::Herz, Rank::Sieben), card(Suit::Eichel, Rank::Koenig),
+        card(Suit::Eichel, Rank::Neun), card(Suit::Gras, Rank::Acht), card(Suit::Gras, Rank::Sieben),
+        card(Suit::Schell, Rank::Acht), card(Suit::Schell, Rank::Zehn),
+    ];
+    assert_eq!(bid(&mut npc, &weak), None);
+}
+
+#[test]
+fn npc_partner_judges_re_by_its_own_share() {
+    let hands = [
+        vec![card(Suit::Eichel, Rank::Ober), card(Suit::Schell, Rank::Ober), card(Suit::Herz, Rank::Ass), card(Suit::Herz, Rank::Zehn), card(Suit::Schell, Rank::Sieben)],
+        vec![card(Suit::Gras, Rank::Ober), card(Suit::Herz, Rank::Unter), card(Suit::Herz, Rank::Neun), card(Suit::Herz, Rank::Acht), card(Suit::Eichel, Rank::Ass)],
+        vec![card(Suit::Gras, Rank::Ass), card(Suit::Gras, Rank::Zehn), card(Suit::Schell, Rank::Zehn), card(Suit::Eichel, Rank::Zehn), card(Suit::Gras, Rank::Koenig)],
+        vec![card(Suit::Schell, Rank::Ass), card(Suit::Schell, Rank::Koenig), card(Suit::Gras, Rank::Neun), card(Suit::Eichel, Rank::Neun), card(Suit::Eichel, Rank::Acht)],
+    ];
+    let game = Game::new(Gamemode::Sauspiel(Suit::Eichel), hands, 0, Some(0));
+    let mut npc = NpcPlayer::new(0, "NPC");
+
+    // Four trumps with the Gras Ober back the game, but are no Kontra
+    assert!(npc.double(&game.view(1), Call::Re).unwrap());
+    assert!(!npc.double(&game.view(1), Call::Kontra).unwrap());
+    assert!(!npc.double(&game.view(0), Call::Re).unwrap());
+}
+
+#[test]
+fn sampled_games_agree_with_the_view() {
+    let hands = crate::deck::Deck::shuffled(9).deal_4x8().unwrap();
+    let mut game = Game::new(Gamemode::Solo(Suit::Eichel), hands, 0, Some(2));
+    for _ in 0..9 {
+        let card = npc_card(&game);
+        game.play(card).unwrap();
+    }
+
+    let view = game.view(1);
+    let mut rng = rand::rng();
+    for _ in 0..20 {
+        let sample = pimc::sample_game(&view, &mut rng).expect("a consistent deal exists");
+        assert_eq!(sample.hand(1), game.hand(1));
+        assert_eq!(sample.tricks(), game.tricks());
+        assert_eq!(sample.current_trick(), game.current_trick());
+        for seat in 0..4 {
+            assert_eq!(sample.hand(seat).len(), game.hand(seat).len());
+        }
+    }
+}
+
+#[test]
+fn pimc_takes_a_trick_it_can_win() {
+    // The declarer plays last and only the Herz Ober takes the 21 points
+    let hands = [
+        vec![card(Suit::Gras, Rank::Acht), card(Suit::Herz, Rank::Acht)],
+        vec![card(Suit::Herz, Rank::Ober), card(Suit::Schell, Rank::Sieben)],
+        vec![card(Suit::Gras, Rank::Ass), card(Suit::Herz, Rank::Neun)],
+        vec![card(Suit::Gras, Rank::Zehn), card(Suit::Herz, Rank::Koenig)],
+    ];
+    let mut game = Game::new(Gamemode::Solo(Suit::Herz), hands, 2, Some(1));
+    game.play(card(Suit::Gras, Rank::Ass)).unwrap();
+    game.play(card(Suit::Gras, Rank::Zehn)).unwrap();
+    game.play(card(Suit::Gras, Rank::Acht)).unwrap();
+
+    let config = PimcConfig { samples: 10, seed: Some(7), ..PimcConfig::default() };
+    let mut pimc = PimcPlayer::new(1, "PIMC", config);
+    let chosen = pimc.play_card(&game.view(1), &game.legal_cards()).unwrap();
+    assert_eq!(chosen, card(Suit::Herz, Rank::Ober));
+}
+
+#[test]
+fn random_player_is_reproducible_and_legal() {
+    let play = |seed: u64| {
+        let mut players: Vec<RandomPlayer> = (0..4).map(|i| RandomPlayer::with_seed(i, "Random", seed + i as u64)).collect();
+        let ruleset = crate::gamemode::Ruleset { ramsch: true, ..crate::gamemode::Ruleset::all() };
+        let [a, b, c, d] = &mut players[..] else { unreachable!() };
+        crate::table::play_round([a, b, c, d], crate::deck::Deck::new().deal_4x8().unwrap(), 0, &ruleset)
+            .expect("random players only choose offered options")
+            .map(|game| (game.calls().to_vec(), game.tricks().to_vec()))
+    };
+
+    for seed in 0..20 {
+        assert_eq!(play(seed * 4), play(seed * 4));
+    }
+}
+
+fn human(input: &str) -> HumanPlayer<std::io::Cursor<Vec<u8>>, Vec<u8>> {
+    HumanPlayer::with_io(0, "Human", std::io::Cursor::new(input.as_bytes().to_vec()), Vec::new())
+}
+
+fn output(player: &HumanPlayer<std::io::Cursor<Vec<u8>>, Vec<u8>>) -> String {
+    String::from_utf8(player.output().clone()).unwrap()
+}
+
+#[test]
+fn human_reprompts_until_a_legal_card_is_named() {
+    let game = gras_trick_game(vec![card(Suit::Gras, Rank::Koenig), card(Suit::Eichel, Rank::Zehn)], 3);
+    let mut player = human("xyz\nlast\nho\ne10\ngras könig\n");
+
+    let chosen = player.play_card(&game.view(1), &game.legal_cards()).unwrap();
+    assert_eq!(chosen, card(Suit::Gras, Rank::Koenig));
+
+    let text = output(&player);
+    assert!(text.contains("  0: Gras König"));
+    assert!(!text.contains("  1: Eichel Zehn"));
+    assert!(text.contains("Unknown card \"xyz\""));
+    assert!(text.contains("No trick has been played yet."));
+    assert!(text.contains("You do not hold Herz Ober."));
+    assert!(text.contains("Eichel Zehn may not be played now."));
+}
+
+#[test]
+fn human_accepts_indices_and_short_names() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 3);
+    let legal = game.legal_cards();
+    assert_eq!(human("1\n").play_card(&game.view(1), &legal).unwrap(), legal[1]);
+    assert_eq!(human("S7\n").play_card(&game.view(1), &legal).unwrap(), card(Suit::Schell, Rank::Sieben));
+    assert_eq!(human(" eichel zehn \n").play_card(&game.view(1), &legal).unwrap(), card(Suit::Eichel, Rank::Zehn));
+}
+
+#[test]
+fn human_bids_by_number_or_name() {
+    let options = [Announcement::Game(Gamemode::Wenz(None)), Announcement::Game(Gamemode::Solo(Suit::Herz))];
+    let view = PlayerView::before_game(0, &[], 0, &[]);
+
+    assert_eq!(human("foo\n9\nherz-solo\n").bid(&view, &options).unwrap(), Some(options[1]));
+    assert_eq!(human("1\n").bid(&view, &options).unwrap(), Some(options[0]));
+    assert_eq!(human("weiter\n").bid(&view, &options).unwrap(), None);
+    assert!(human("maybe\nJ\n").klopfen(&view).unwrap());
+}
+
+#[test]
+fn human_without_input_fails() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 3);
+    let result = human("nonsense\n").play_card(&game.view(1), &game.legal_cards());
+    assert!(matches!(result, Err(PlayerError::Io(_))));
+}
+
+#[test]
+fn replaying_a_round_reproduces_it() {
+    let ruleset = crate::gamemode::Ruleset { ramsch: true, ..crate::gamemode::Ruleset::all() };
+    let hands = crate::deck::Deck::new().deal_4x8().unwrap();
+    let mut players: Vec<RandomPlayer> = (0..4).map(|i| RandomPlayer::with_seed(i, "Random", 40 + i as u64)).collect();
+    let [a, b, c, d] = &mut players[..] else { unreachable!() };
+    let recorded = crate::table::play_round([a, b, c, d], hands.clone(), 1, &ruleset).unwrap().unwrap();
+
+    let mut replays: Vec<ReplayPlayer> = (0..4).map(|s| ReplayPlayer::from_game(s as u32, "Replay", &recorded, s)).collect();
+    let [a, b, c, d] = &mut replays[..] else { unreachable!() };
+    let replayed = crate::table::play_round([a, b, c, d], hands.clone(), 1, &ruleset).unwrap().unwrap();
+
+    assert_eq!(replayed.calls(), recorded.calls());
+    assert_eq!(replayed.tricks(), recorded.tricks());
+    assert!(replays.iter().all(|r| r.is_done() && r.finish().is_ok()));
+
+    // The same recording no longer fits once the hands are passed on
+    let mut replays: Vec<ReplayPlayer> = (0..4).map(|s| ReplayPlayer::from_game(s as u32, "Replay", &recorded, s)).collect();
+    let [a, b, c, d] = &mut replays[..] else { unreachable!() };
+    let mut moved = hands;
+    moved.rotate_left(1);
+    assert!(crate::table::play_round([a, b, c, d], moved, 1, &ruleset).is_err());
+}
+
+#[test]
+fn replay_runs_out_loudly() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 3);
+    let mut replay = ReplayPlayer::new(1, "Replay", vec![], vec![card(Suit::Schell, Rank::Sieben)]);
+    let view = game.view(1);
+
+    assert!(matches!(replay.bid(&view, &[]), Err(PlayerError::ReplayExhausted)));
+    assert_eq!(replay.play_card(&view, &game.legal_cards()).unwrap(), card(Suit::Schell, Rank::Sieben));
+    assert!(matches!(replay.play_card(&view, &game.legal_cards()), Err(PlayerError::ReplayExhausted)));
+}
+
+#[test]
+fn replay_keeps_to_the_recorded_order() {
+    let game = gras_trick_game(vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Schell, Rank::Sieben)], 3);
+    let view = game.view(1);
+    let kontra_first = || ReplayPlayer::new(1, "Replay", vec![Call::Kontra, Call::Weiter], vec![]);
+
+    // A Kontra recorded before the bid cannot answer Klopfen or the bid
+    assert!(matches!(kontra_first().klopfen(&view), Err(PlayerError::ReplayMismatch(Call::Kontra))));
+    assert!(matches!(kontra_first().bid(&view, &[]), Err(PlayerError::ReplayMismatch(Call::Kontra))));
+    assert!(matches!(kontra_first().double(&view, Call::Re), Err(PlayerError::ReplayMismatch(Call::Kontra))));
+
+    // Not knocking leaves the bid in place
+    let mut replay = ReplayPlayer::new(1, "Replay", vec![Call::Weiter, Call::Kontra], vec![]);
+    assert!(!replay.klopfen(&view).unwrap());
+    assert_eq!(replay.bid(&view, &[]).unwrap(), None);
+
+    // A Kontra that was never asked for is left over
+    assert!(matches!(replay.play_card(&view, &game.legal_cards()), Err(PlayerError::ReplayMismatch(Call::Kontra))));
+    assert!(matches!(replay.finish(), Err(PlayerError::ReplayLeftover(1))));
+}
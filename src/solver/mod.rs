@@ -0,0 +1,276 @@
+//! Exact double-dummy analysis of a game with all hands known.
+
+use std::collections::HashMap;
+
+use crate::deck::{Card, CardSet, Deck};
+use crate::game::{Game, Seat, finished_cards};
+use crate::gamemode::GameRules;
+
+/// What the declaring side is playing for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Goal {
+    /// As many Augen as possible.
+    Points,
+    /// No trick at all (Bettel); the declarer minimises the tricks taken.
+    NoTricks,
+    /// Every trick (Tout, Sie).
+    AllTricks,
+}
+
+impl Goal {
+    /// Derives the goal from how `rules` decide the game.
+    pub fn for_rules(rules: &dyn GameRules) -> Self {
+        if rules.declarer_wins(0, 0) {
+            Goal::NoTricks
+        } else if !rules.declarer_wins(120, 7) {
+            Goal::AllTricks
+        } else {
+            Goal::Points
+        }
+    }
+}
+
+/// Result of a double-dummy search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    pub goal: Goal,
+    /// Augen (for [`Goal::Points`]) or tricks the declaring side ends the
+    /// game with when both sides play perfectly, including what it has
+    /// already taken.
+    pub value: u8,
+    /// A card for the player to move that reaches `value`, `None` once the
+    /// game is over.
+    pub best_card: Option<Card>,
+}
+
+/// Solves `game` from its current position with every hand open.
+///
+/// Returns `None` for games without a declarer, such as Ramsch.
+pub fn solve(game: &Game) -> Option<Solution> {
+    let mut search = Search::new(game)?;
+    let moves = search.ordered_moves();
+    let maximizing = search.maximizing();
+
+    let mut best: Option<(Card, u8)> = None;
+    let (mut alpha, mut beta) = (0, u8::MAX);
+    for card in moves {
+        let value = search.try_move(card, alpha, beta);
+        let better = match best {
+            None => true,
+            Some((_, v)) => if maximizing { value > v } else { value < v },
+        };
+        if better {
+            best = Some((card, value));
+            if maximizing { alpha = value } else { beta = value }
+        }
+    }
+
+    Some(Solution {
+        goal: search.goal,
+        value: search.taken + best.map_or(0, |(_, v)| v),
+        best_card: best.map(|(c, _)| c),
+    })
+}
+
+/// Exact value of every legal card for the player to move.
+///
+/// Values are totals for the declaring side as in [`Solution::value`].
+pub fn move_values(game: &Game) -> Option<Vec<(Card, u8)>> {
+    let mut search = Search::new(game)?;
+    let taken = search.taken;
+    Some(
+        search
+            .ordered_moves()
+            .into_iter()
+            .map(|card| (card, taken + search.try_move(card, 0, u8::MAX)))
+            .collect(),
+    )
+}
+
+#[derive(Clone, Copy)]
+struct Bounds {
+    lower: u8,
+    upper: u8,
+}
+
+struct Search<'a> {
+    rules: &'a dyn GameRules,
+    goal: Goal,
+    points: [u8; 32],
+    // strength[card][led], only to order the moves
+    strength: Vec<[u16; 32]>,
+    declarer_side: [bool; 4],
+    hands: [CardSet; 4],
+    trick: Vec<(Seat, usize)>,
+    leader: Seat,
+    taken: u8,
+    // Cards of the finished tricks, for `legal_cards`
+    played: Vec<Card>,
+    // Whether a finished trick was led in the called suit; it changes which
+    // cards are legal, so it is part of the table key
+    called_led: bool,
+    table: HashMap<(CardSet, Seat, bool), Bounds>,
+}
+
+impl<'a> Search<'a> {
+    fn new(game: &'a Game) -> Option<Self> {
+        game.declarer()?;
+        let rules = game.rules();
+        let goal = Goal::for_rules(rules);
+        let cards: Vec<Card> = Deck::new().iter().copied().collect();
+
+        let mut points = [0; 32];
+        let mut strength = vec![[0; 32]; 32];
+        for card in &cards {
+            points[card.index()] = rules.card_points(card);
+            for led in &cards {
+                strength[card.index()][led.index()] = rules.card_strength(card, led);
+            }
+        }
+
+        let declarer_side: [bool; 4] = std::array::from_fn(|s| game.is_declarer_side(s));
+        let hands = std::array::from_fn(|s| CardSet::from(game.hand(s)));
+        let trick = game.current_trick().plays().map(|(s, c)| (s, c.index())).collect();
+        let played = finished_cards(game.tricks());
+        let called_led = game.tricks().iter().any(|t| led_called_suit(rules, &t.cards()[0]));
+        let taken = match goal {
+            Goal::Points => (0..4).filter(|&s| declarer_side[s]).map(|s| game.points(s)).sum(),
+            _ => (0..4).filter(|&s| declarer_side[s]).map(|s| game.tricks_won(s) as u8).sum(),
+        };
+
+        Some(Self {
+            rules,
+            goal,
+            points,
+            strength,
+            declarer_side,
+            hands,
+            trick,
+            leader: game.current_trick().leader(),
+            taken,
+            played,
+            called_led,
+            table: HashMap::new(),
+        })
+    }
+
+    fn to_move(&self) -> Seat {
+        (self.leader + self.trick.len()) % 4
+    }
+
+    fn maximizing(&self) -> bool {
+        self.declarer_side[self.to_move()] != (self.goal == Goal::NoTricks)
+    }
+
+    // Legal cards, strongest first so that winning tries are searched early
+    fn ordered_moves(&self) -> Vec<Card> {
+        let seat = self.to_move();
+        if self.hands[seat].is_empty() {
+            return Vec::new();
+        }
+        let trick: Vec<Card> = self.trick.iter().filter_map(|&(_, i)| Card::from_index(i)).collect();
+        let mut moves = self.rules.legal_cards(&self.hands[seat].to_vec(), &trick, &self.played);
+        let led = self.trick.first().map(|&(_, i)| i);
+        moves.sort_by_key(|c| {
+            let i = c.index();
+            std::cmp::Reverse((self.strength[i][led.unwrap_or(i)], self.points[i]))
+        });
+        moves
+    }
+
+    // Plays `card`, searches on and takes it back; returns the future value
+    fn try_move(&mut self, card: Card, alpha: u8, beta: u8) -> u8 {
+        let seat = self.to_move();
+        let i = card.index();
+        self.hands[seat].remove(card);
+        self.trick.push((seat, i));
+
+        let value = if self.trick.len() < 4 {
+            self.search(alpha, beta)
+        } else {
+            let trick = std::mem::take(&mut self.trick);
+            let cards: Vec<Card> = trick.iter().filter_map(|&(_, c)| Card::from_index(c)).collect();
+            let (winner, _) = trick[self.rules.winning_index(&cards)];
+            let gain = if self.declarer_side[winner] {
+                match self.goal {
+                    Goal::Points => trick.iter().map(|&(_, c)| self.points[c]).sum(),
+                    _ => 1,
+                }
+            } else {
+                0
+            };
+            let called_led = self.called_led;
+            self.called_led |= led_called_suit(self.rules, &cards[0]);
+            self.played.extend(cards);
+            let leader = std::mem::replace(&mut self.leader, winner);
+            let future = self.search(alpha.saturating_sub(gain), beta.saturating_sub(gain));
+            self.leader = leader;
+            self.played.truncate(self.played.len() - 4);
+            self.called_led = called_led;
+            self.trick = trick;
+            gain + future
+        };
+
+        self.trick.pop();
+        self.hands[seat].insert(card);
+        value
+    }
+
+    fn search(&mut self, mut alpha: u8, mut beta: u8) -> u8 {
+        let boundary = self.trick.is_empty();
+        let key = (self.hands.iter().fold(CardSet::EMPTY, |all, &h| all | h), self.leader, self.called_led);
+        if boundary {
+            if key.0.is_empty() {
+                return 0;
+            }
+            if let Some(bounds) = self.table.get(&key) {
+                if bounds.lower == bounds.upper || bounds.lower >= beta {
+                    return bounds.lower;
+                }
+                if bounds.upper <= alpha {
+                    return bounds.upper;
+                }
+                alpha = alpha.max(bounds.lower);
+                beta = beta.min(bounds.upper);
+            }
+        }
+
+        let (alpha_in, beta_in) = (alpha, beta);
+        let maximizing = self.maximizing();
+        let mut best = if maximizing { 0 } else { u8::MAX };
+        for card in self.ordered_moves() {
+            let value = self.try_move(card, alpha, beta);
+            if maximizing {
+                best = best.max(value);
+                alpha = alpha.max(value);
+            } else {
+                best = best.min(value);
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        if boundary {
+            let entry = self.table.entry(key).or_insert(Bounds { lower: 0, upper: u8::MAX });
+            if best <= alpha_in {
+                entry.upper = entry.upper.min(best);
+            } else if best >= beta_in {
+                entry.lower = entry.lower.max(best);
+            } else {
+                *entry = Bounds { lower: best, upper: best };
+            }
+        }
+        best
+    }
+}
+
+fn led_called_suit(rules: &dyn GameRules, lead: &Card) -> bool {
+    rules.called_card().is_some_and(|called| rules.follows(lead, &called))
+}
+
+#[cfg(test)]
+mod tests;
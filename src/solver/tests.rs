@@ -0,0 +1,158 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use super::*;
+use crate::deck::{Rank, Suit};
+use crate::gamemode::{Announcement, Gamemode};
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+// Plain minimax over the engine, without any pruning
+fn brute_force(game: &Game, goal: Goal) -> u8 {
+    let Some(seat) = game.current_player() else {
+        let side = (0..4).filter(|&s| game.is_declarer_side(s));
+        return match goal {
+            Goal::Points => side.map(|s| game.points(s)).sum(),
+            _ => side.map(|s| game.tricks_won(s) as u8).sum(),
+        };
+    };
+    let values = game.legal_cards().into_iter().map(|c| {
+        let mut next = game.clone();
+        next.play(c).unwrap();
+        brute_force(&next, goal)
+    });
+    if game.is_declarer_side(seat) != (goal == Goal::NoTricks) {
+        values.max().unwrap()
+    } else {
+        values.min().unwrap()
+    }
+}
+
+fn endgame(rules: impl GameRules + 'static, seed: u64, cards_left: usize) -> Game {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut cards: Vec<Card> = Deck::new().iter().copied().collect();
+    cards.shuffle(&mut rng);
+    let hands = std::array::from_fn(|s| cards[s * 8..(s + 1) * 8].to_vec());
+    let mut game = Game::new(rules, hands, 0, Some(1));
+    while game.hand(3).len() > cards_left {
+        let card = *game.legal_cards().choose(&mut rng).unwrap();
+        game.play(card).unwrap();
+    }
+    game
+}
+
+#[test]
+fn goal_follows_rules() {
+    assert_eq!(Goal::for_rules(&Gamemode::Solo(Suit::Herz)), Goal::Points);
+    assert_eq!(Goal::for_rules(&Gamemode::Bettel), Goal::NoTricks);
+    assert_eq!(Goal::for_rules(&Announcement::Tout(Gamemode::Wenz(None))), Goal::AllTricks);
+}
+
+#[test]
+fn matches_brute_force_in_endgames() {
+    let modes = [
+        Announcement::Game(Gamemode::Sauspiel(Suit::Eichel)),
+        Announcement::Game(Gamemode::Solo(Suit::Gras)),
+        Announcement::Game(Gamemode::Wenz(None)),
+        Announcement::Game(Gamemode::Bettel),
+        Announcement::Tout(Gamemode::Solo(Suit::Schell)),
+    ];
+    for seed in 0..10 {
+        for mode in modes {
+            let mut game = endgame(mode, seed, 3);
+            // Also start in the middle of a trick
+            if seed % 2 == 1 {
+                let card = game.legal_cards()[0];
+                game.play(card).unwrap();
+            }
+
+            let solution = solve(&game).unwrap();
+            assert_eq!(solution.value, brute_force(&game, solution.goal), "{} seed {}", mode, seed);
+
+            let best = solution.best_card.unwrap();
+            let mut after = game.clone();
+            after.play(best).unwrap();
+            assert_eq!(brute_force(&after, solution.goal), solution.value, "{} seed {}", mode, seed);
+        }
+    }
+}
+
+// A house rule where the card with the fewest Augen takes the trick
+struct CheapestWins;
+
+impl std::fmt::Display for CheapestWins {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cheapest wins")
+    }
+}
+
+impl GameRules for CheapestWins {
+    fn is_trump(&self, card: &Card) -> bool {
+        Gamemode::Solo(Suit::Herz).is_trump(card)
+    }
+
+    fn card_strength(&self, card: &Card, led: &Card) -> u16 {
+        Gamemode::Solo(Suit::Herz).card_strength(card, led)
+    }
+
+    fn winning_index(&self, trick: &[Card]) -> usize {
+        (0..trick.len()).min_by_key(|&i| trick[i].rank.points()).unwrap_or(0)
+    }
+}
+
+#[test]
+fn uses_the_rules_to_decide_tricks() {
+    for seed in 0..10 {
+        let game = endgame(CheapestWins, seed, 3);
+        let solution = solve(&game).unwrap();
+        assert_eq!(solution.value, brute_force(&game, solution.goal), "seed {}", seed);
+    }
+}
+
+#[test]
+fn move_values_rank_every_legal_card() {
+    let game = endgame(Gamemode::Solo(Suit::Eichel), 3, 3);
+    let solution = solve(&game).unwrap();
+    let values = move_values(&game).unwrap();
+
+    assert_eq!(values.len(), game.legal_cards().len());
+    for (card, value) in &values {
+        let mut after = game.clone();
+        after.play(*card).unwrap();
+        assert_eq!(*value, brute_force(&after, Goal::Points));
+    }
+    assert_eq!(values.iter().map(|&(_, v)| v).max(), Some(solution.value));
+}
+
+#[test]
+fn finds_the_only_winning_line() {
+    // Declarer (seat 0) must pull the last trump before cashing the Sau
+    let hands = [
+        vec![card(Suit::Herz, Rank::Ober), card(Suit::Gras, Rank::Ass)],
+        vec![card(Suit::Herz, Rank::Sieben), card(Suit::Schell, Rank::Neun)],
+        vec![card(Suit::Eichel, Rank::Zehn), card(Suit::Eichel, Rank::Sieben)],
+        vec![card(Suit::Schell, Rank::Sieben), card(Suit::Schell, Rank::Acht)],
+    ];
+    let game = Game::new(Gamemode::Solo(Suit::Herz), hands, 0, Some(0));
+    let solution = solve(&game).unwrap();
+
+    assert_eq!(solution.best_card, Some(card(Suit::Herz, Rank::Ober)));
+    assert_eq!(solution.value, 24);
+}
+
+#[test]
+fn solves_a_midgame() {
+    let game = endgame(Gamemode::Sauspiel(Suit::Gras), 11, 6);
+    let solution = solve(&game).unwrap();
+    assert!(solution.value <= 120);
+    assert!(game.legal_cards().contains(&solution.best_card.unwrap()));
+}
+
+#[test]
+fn ramsch_has_no_solution() {
+    let game = endgame(Gamemode::Ramsch, 0, 3);
+    assert!(solve(&game).is_none());
+}
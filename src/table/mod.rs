@@ -0,0 +1,56 @@
+use crate::deck::Card;
+use crate::game::{Game, Seat};
+use crate::gamemode::{Announcement, Ruleset, available_announcements};
+use crate::player::{Player, PlayerError};
+
+mod round;
+use round::{Answer, Ask, Round};
+
+mod timed;
+pub use timed::{Fallback, MoveLimit, block_on, play_round_async};
+
+/// The four seats in playing order, starting with `first`.
+pub fn seats_from(first: Seat) -> impl Iterator<Item = Seat> {
+    (0..4).map(move |i| (first + i) % 4)
+}
+
+/// Announcements `hand` may make when `to_beat` is the highest game so far.
+pub fn bid_options(hand: &[Card], ruleset: &Ruleset, to_beat: Option<Announcement>) -> Vec<Announcement> {
+    let min = to_beat.map_or(0, |a| a.precedence() + 1);
+    available_announcements(hand, ruleset)
+        .into_iter()
+        .filter(|a| a.precedence() >= min)
+        .collect()
+}
+
+/// Plays one round: Klopfen, the auction, Kontra and Re, then the cards.
+///
+/// The seat after `dealer` is asked first and leads the first trick. In the
+/// auction every seat is asked once and may only announce a game that
+/// outbids the highest one so far. If nobody announces a game, a Ramsch is
+/// played when `ruleset` allows it; otherwise the cards are thrown in and
+/// `None` is returned.
+///
+/// A player returning a card or game it was not offered ends the round with
+/// an error.
+pub fn play_round(
+    players: [&mut dyn Player; 4],
+    hands: [Vec<Card>; 4],
+    dealer: Seat,
+    ruleset: &Ruleset,
+) -> Result<Option<Game>, PlayerError> {
+    let mut round = Round::new(hands, dealer, ruleset);
+    while let Some(ask) = round.ask() {
+        let answer = match ask {
+            Ask::Klopfen(seat, view) => Answer::Klopfen(players[seat].klopfen(&view)?),
+            Ask::Bid(seat, view, options) => Answer::Bid(players[seat].bid(&view, &options)?),
+            Ask::Double(seat, view, call) => Answer::Double(players[seat].double(&view, call)?),
+            Ask::Card(seat, view, legal) => Answer::Card(players[seat].play_card(&view, &legal)?),
+        };
+        round.answer(answer)?;
+    }
+    Ok(round.finish())
+}
+
+#[cfg(test)]
+mod tests;
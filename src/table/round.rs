@@ -0,0 +1,179 @@
+use crate::deck::Card;
+use crate::game::{Call, Game, PlayerView, Seat, next_seat};
+use crate::gamemode::{Announcement, Gamemode, Ruleset};
+use crate::player::PlayerError;
+use crate::table::{bid_options, seats_from};
+
+/// A decision the round waits for.
+pub(crate) enum Ask {
+    Klopfen(Seat, PlayerView),
+    Bid(Seat, PlayerView, Vec<Announcement>),
+    Double(Seat, PlayerView, Call),
+    Card(Seat, PlayerView, Vec<Card>),
+}
+
+/// A seat's answer to the last [`Ask`].
+pub(crate) enum Answer {
+    Klopfen(bool),
+    Bid(Option<Announcement>),
+    Double(bool),
+    Card(Card),
+}
+
+// Where the round stands; the index counts seats from the leader
+#[derive(Clone, Copy)]
+enum Phase {
+    Klopfen(usize),
+    Auction(usize),
+    Double(Call, usize),
+    Cards,
+    Over,
+}
+
+/// The flow of one round: Klopfen, the auction, Kontra and Re, then the
+/// cards. The drivers only supply the answers.
+pub(crate) struct Round<'r> {
+    ruleset: &'r Ruleset,
+    leader: Seat,
+    phase: Phase,
+    hands: [Vec<Card>; 4],
+    calls: Vec<(Seat, Call)>,
+    best: Option<(Seat, Announcement)>,
+    game: Option<Game>,
+}
+
+impl<'r> Round<'r> {
+    pub(crate) fn new(hands: [Vec<Card>; 4], dealer: Seat, ruleset: &'r Ruleset) -> Self {
+        Self {
+            ruleset,
+            leader: next_seat(dealer),
+            phase: Phase::Klopfen(0),
+            hands,
+            calls: Vec::new(),
+            best: None,
+            game: None,
+        }
+    }
+
+    /// The next decision, `None` once the round is over.
+    pub(crate) fn ask(&self) -> Option<Ask> {
+        match self.phase {
+            Phase::Klopfen(i) => {
+                let seat = self.seat(i);
+                let first_cards = &self.hands[seat][..self.hands[seat].len().min(4)];
+                Some(Ask::Klopfen(seat, PlayerView::before_game(seat, first_cards, self.leader, &self.calls)))
+            }
+            Phase::Auction(i) => {
+                let seat = self.seat(i);
+                let view = PlayerView::before_game(seat, &self.hands[seat], self.leader, &self.calls);
+                Some(Ask::Bid(seat, view, self.options(seat)))
+            }
+            Phase::Double(call, i) => {
+                let seat = self.seat(i);
+                Some(Ask::Double(seat, self.game.as_ref()?.view(seat), call))
+            }
+            Phase::Cards => {
+                let game = self.game.as_ref()?;
+                let seat = game.current_player()?;
+                Some(Ask::Card(seat, game.view(seat), game.legal_cards()))
+            }
+            Phase::Over => None,
+        }
+    }
+
+    /// Takes the answer to [`Round::ask`]; a game or card that was not
+    /// offered is an error.
+    ///
+    /// # Panics
+    ///
+    /// If the answer is of another kind than the question.
+    pub(crate) fn answer(&mut self, answer: Answer) -> Result<(), PlayerError> {
+        match (self.phase, answer) {
+            (Phase::Klopfen(i), Answer::Klopfen(klopfen)) => {
+                if klopfen {
+                    self.calls.push((self.seat(i), Call::Klopfen));
+                }
+                self.phase = if i < 3 { Phase::Klopfen(i + 1) } else { Phase::Auction(0) };
+            }
+            (Phase::Auction(i), Answer::Bid(bid)) => {
+                let seat = self.seat(i);
+                match bid {
+                    Some(bid) if self.options(seat).contains(&bid) => {
+                        self.calls.push((seat, Call::Announce(bid)));
+                        self.best = Some((seat, bid));
+                    }
+                    Some(bid) => return Err(PlayerError::IllegalBid(bid)),
+                    None => self.calls.push((seat, Call::Weiter)),
+                }
+                if i < 3 {
+                    self.phase = Phase::Auction(i + 1);
+                } else {
+                    self.start_game();
+                }
+            }
+            (Phase::Double(call, i), Answer::Double(double)) => {
+                if !double {
+                    self.phase = self.next_doubler(call, i + 1);
+                } else {
+                    let seat = self.seat(i);
+                    if let Some(game) = &mut self.game {
+                        game.record_call(seat, call);
+                    }
+                    self.phase = if call == Call::Kontra { self.next_doubler(Call::Re, 0) } else { Phase::Cards };
+                }
+            }
+            (Phase::Cards, Answer::Card(card)) => {
+                if let Some(game) = &mut self.game {
+                    game.play(card).map_err(|_| PlayerError::IllegalCard(card))?;
+                }
+            }
+            _ => panic!("the answer does not match the question"),
+        }
+        Ok(())
+    }
+
+    /// The game played, `None` if the cards were thrown in.
+    pub(crate) fn finish(self) -> Option<Game> {
+        self.game
+    }
+
+    fn seat(&self, i: usize) -> Seat {
+        (self.leader + i) % 4
+    }
+
+    fn options(&self, seat: Seat) -> Vec<Announcement> {
+        bid_options(&self.hands[seat], self.ruleset, self.best.map(|(_, a)| a))
+    }
+
+    // The announced game, a Ramsch, or none if the cards are thrown in
+    fn start_game(&mut self) {
+        let (rules, declarer) = match self.best {
+            Some((seat, announcement)) => (announcement, Some(seat)),
+            None if self.ruleset.ramsch => (Announcement::Game(Gamemode::Ramsch), None),
+            None => {
+                self.phase = Phase::Over;
+                return;
+            }
+        };
+        let mut game = Game::new(rules, std::mem::take(&mut self.hands), self.leader, declarer);
+        for (seat, call) in std::mem::take(&mut self.calls) {
+            game.record_call(seat, call);
+        }
+        self.game = Some(game);
+        self.phase = if declarer.is_some() { self.next_doubler(Call::Kontra, 0) } else { Phase::Cards };
+    }
+
+    // Kontra goes to the other side and Re to the declaring side, from the
+    // `from`-th seat on; once nobody is left the cards are played
+    fn next_doubler(&self, call: Call, from: usize) -> Phase {
+        let Some(game) = &self.game else {
+            return Phase::Over;
+        };
+        let declarer_side = call == Call::Re;
+        seats_from(self.leader)
+            .enumerate()
+            .skip(from)
+            .find(|&(_, seat)| game.is_declarer_side(seat) == declarer_side)
+            .map_or(Phase::Cards, |(i, _)| Phase::Double(call, i))
+    }
+}
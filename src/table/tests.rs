@@ -0,0 +1,231 @@
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::deck::{Deck, Suit};
+use crate::game::{Call, PlayerView};
+use crate::gamemode::Gamemode;
+use crate::player::{AsyncPlayer, Blocking, NpcPlayer, PlayerFuture};
+
+fn dealt_hands() -> [Vec<Card>; 4] {
+    Deck::new().deal_4x8().expect("deck should contain 32 cards")
+}
+
+#[derive(Default)]
+struct TestPlayer {
+    klopfen: bool,
+    bid: Option<Announcement>,
+    double: bool,
+    cheat: bool,
+}
+
+impl Player for TestPlayer {
+    fn klopfen(&mut self, _view: &PlayerView) -> Result<bool, PlayerError> {
+        Ok(self.klopfen)
+    }
+
+    fn bid(&mut self, _view: &PlayerView, _options: &[Announcement]) -> Result<Option<Announcement>, PlayerError> {
+        Ok(self.bid)
+    }
+
+    fn double(&mut self, _view: &PlayerView, _call: Call) -> Result<bool, PlayerError> {
+        Ok(self.double)
+    }
+
+    fn play_card(&mut self, view: &PlayerView, legal: &[Card]) -> Result<Card, PlayerError> {
+        if self.cheat {
+            Ok(*view.hand().iter().find(|c| !legal.contains(c)).unwrap_or(&legal[0]))
+        } else {
+            Ok(legal[0])
+        }
+    }
+}
+
+#[test]
+fn cards_are_thrown_in_without_announcement() {
+    let mut players: [TestPlayer; 4] = Default::default();
+    let [a, b, c, d] = &mut players;
+
+    let round = play_round([a, b, c, d], dealt_hands(), 0, &Ruleset::default()).unwrap();
+    assert!(round.is_none());
+}
+
+#[test]
+fn plays_ramsch_when_everyone_passes() {
+    let mut players: [TestPlayer; 4] = Default::default();
+    let [a, b, c, d] = &mut players;
+
+    let ruleset = Ruleset { ramsch: true, ..Ruleset::default() };
+    let game = play_round([a, b, c, d], dealt_hands(), 0, &ruleset).unwrap().unwrap();
+    assert!(game.is_finished());
+    assert_eq!(game.declarer(), None);
+    assert_eq!(game.rules().to_string(), "Ramsch");
+    assert_eq!(game.tricks()[0].leader(), 1);
+}
+
+#[test]
+fn auction_klopfen_and_kontra_are_recorded() {
+    let wenz = Announcement::Game(Gamemode::Wenz(None));
+    let solo = Announcement::Game(Gamemode::Solo(Suit::Eichel));
+    let mut p0 = TestPlayer { bid: Some(wenz), ..Default::default() };
+    let mut p1 = TestPlayer { bid: Some(solo), ..Default::default() };
+    let mut p2 = TestPlayer { double: true, ..Default::default() };
+    let mut p3 = TestPlayer { klopfen: true, ..Default::default() };
+
+    let game = play_round([&mut p0, &mut p1, &mut p2, &mut p3], dealt_hands(), 3, &Ruleset::default())
+        .unwrap()
+        .unwrap();
+
+    assert!(game.is_finished());
+    assert_eq!(game.declarer(), Some(1));
+    assert_eq!(game.rules().to_string(), "Eichel-Solo");
+    assert_eq!(game.calls(), &[
+        (3, Call::Klopfen),
+        (0, Call::Announce(wenz)),
+        (1, Call::Announce(solo)),
+        (2, Call::Weiter),
+        (3, Call::Weiter),
+        (2, Call::Kontra),
+    ]);
+}
+
+#[test]
+fn re_is_only_offered_after_kontra() {
+    let solo = Announcement::Game(Gamemode::Solo(Suit::Eichel));
+    let mut p0 = TestPlayer { double: true, ..Default::default() };
+    let mut p1 = TestPlayer { bid: Some(solo), double: true, ..Default::default() };
+    let mut p2 = TestPlayer { double: true, ..Default::default() };
+    let mut p3 = TestPlayer::default();
+
+    let game = play_round([&mut p0, &mut p1, &mut p2, &mut p3], dealt_hands(), 3, &Ruleset::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(&game.calls()[4..], &[(0, Call::Kontra), (1, Call::Re)]);
+
+    p0.double = false;
+    p2.double = false;
+    let game = play_round([&mut p0, &mut p1, &mut p2, &mut p3], dealt_hands(), 3, &Ruleset::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(game.calls().len(), 4);
+}
+
+#[test]
+fn rejects_bids_that_do_not_outbid() {
+    let mut p0 = TestPlayer { bid: Some(Announcement::Game(Gamemode::Solo(Suit::Gras))), ..Default::default() };
+    let mut p1 = TestPlayer { bid: Some(Announcement::Game(Gamemode::Wenz(None))), ..Default::default() };
+    let mut p2 = TestPlayer::default();
+    let mut p3 = TestPlayer::default();
+
+    let result = play_round([&mut p0, &mut p1, &mut p2, &mut p3], dealt_hands(), 3, &Ruleset::default());
+    assert!(matches!(result, Err(PlayerError::IllegalBid(_))));
+}
+
+#[test]
+fn rejects_illegal_cards() {
+    let mut p0 = TestPlayer { bid: Some(Announcement::Game(Gamemode::Solo(Suit::Herz))), ..Default::default() };
+    let mut p1 = TestPlayer { cheat: true, ..Default::default() };
+    let mut p2 = TestPlayer { cheat: true, ..Default::default() };
+    let mut p3 = TestPlayer { cheat: true, ..Default::default() };
+
+    let result = play_round([&mut p0, &mut p1, &mut p2, &mut p3], dealt_hands(), 3, &Ruleset::default());
+    assert!(matches!(result, Err(PlayerError::IllegalCard(_))));
+}
+
+#[test]
+fn bid_options_must_outbid() {
+    let hand = &dealt_hands()[0];
+    let all = bid_options(hand, &Ruleset::default(), None);
+    let over_wenz = bid_options(hand, &Ruleset::default(), Some(Announcement::Game(Gamemode::Wenz(None))));
+
+    assert!(all.len() > over_wenz.len());
+    assert!(over_wenz.iter().all(|a| a.precedence() > Gamemode::Wenz(None).precedence()));
+    assert!(over_wenz.contains(&Announcement::Game(Gamemode::Solo(Suit::Schell))));
+}
+
+#[test]
+fn npcs_play_full_rounds() {
+    let mut npcs: Vec<NpcPlayer> = (0..4).map(|i| NpcPlayer::new(i, format!("NPC {}", i))).collect();
+    let ruleset = Ruleset { ramsch: true, ..Ruleset::all() };
+
+    for dealer in 0..4 {
+        let hands = Deck::shuffled(dealer as u64).deal_4x8().unwrap();
+        let [a, b, c, d] = &mut npcs[..] else { unreachable!() };
+        let game = play_round([a, b, c, d], hands, dealer, &ruleset).unwrap().unwrap();
+        assert!(game.is_finished());
+    }
+}
+
+// Answers the auction at once but takes `delay` for every card (forever if `None`)
+struct SlowPlayer {
+    delay: Option<Duration>,
+    disconnected: bool,
+}
+
+impl AsyncPlayer for SlowPlayer {
+    fn bid(&mut self, _view: PlayerView, _options: Vec<Announcement>) -> PlayerFuture<'_, Option<Announcement>> {
+        Box::pin(std::future::ready(Ok(None)))
+    }
+
+    fn play_card(&mut self, _view: PlayerView, legal: Vec<Card>) -> PlayerFuture<'_, Card> {
+        if self.disconnected {
+            return Box::pin(std::future::ready(Err(PlayerError::Disconnected)));
+        }
+        let start = Instant::now();
+        let delay = self.delay;
+        Box::pin(std::future::poll_fn(move |cx| match delay {
+            Some(delay) if start.elapsed() >= delay => Poll::Ready(Ok(legal[0])),
+            Some(_) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            None => Poll::Pending,
+        }))
+    }
+}
+
+fn timed_round(slow: &mut SlowPlayer, fallback: Fallback) -> Result<Option<Game>, PlayerError> {
+    let mut npcs: Vec<Blocking<NpcPlayer>> = (0..3).map(|i| Blocking(NpcPlayer::new(i, "NPC"))).collect();
+    let [a, b, c] = &mut npcs[..] else { unreachable!() };
+    let ruleset = Ruleset { ramsch: true, ..Ruleset::default() };
+    let limit = MoveLimit { time: Duration::from_millis(10), fallback };
+    block_on(play_round_async([a, b, c, slow], dealt_hands(), 0, &ruleset, Some(limit)))
+}
+
+#[test]
+fn async_round_matches_the_synchronous_one() {
+    let ruleset = Ruleset::all();
+    let mut npcs: Vec<NpcPlayer> = (0..4).map(|i| NpcPlayer::new(i, "NPC")).collect();
+    let [a, b, c, d] = &mut npcs[..] else { unreachable!() };
+    let sync = play_round([a, b, c, d], dealt_hands(), 2, &ruleset).unwrap();
+
+    let mut npcs: Vec<Blocking<NpcPlayer>> = (0..4).map(|i| Blocking(NpcPlayer::new(i, "NPC"))).collect();
+    let [a, b, c, d] = &mut npcs[..] else { unreachable!() };
+    let round = play_round_async([a, b, c, d], dealt_hands(), 2, &ruleset, None);
+    fn assert_send<T: Send>(_: &T) {}
+    assert_send(&round);
+    let async_game = block_on(round).unwrap();
+
+    assert_eq!(sync.map(|g| g.tricks().to_vec()), async_game.map(|g| g.tricks().to_vec()));
+}
+
+#[test]
+fn timed_out_moves_fall_back() {
+    let mut stalling = SlowPlayer { delay: None, disconnected: false };
+    let game = timed_round(&mut stalling, Fallback::AutoPlay).unwrap().unwrap();
+    assert!(game.is_finished());
+
+    let result = timed_round(&mut stalling, Fallback::Forfeit);
+    assert!(matches!(result, Err(PlayerError::Timeout(3))));
+
+    let mut slow = SlowPlayer { delay: Some(Duration::from_millis(20)), disconnected: false };
+    let game = timed_round(&mut slow, Fallback::Pause).unwrap().unwrap();
+    assert!(game.is_finished());
+}
+
+#[test]
+fn disconnected_seats_are_played_for_or_end_the_round() {
+    let mut gone = SlowPlayer { delay: None, disconnected: true };
+    assert!(timed_round(&mut gone, Fallback::AutoPlay).unwrap().unwrap().is_finished());
+    assert!(matches!(timed_round(&mut gone, Fallback::Pause), Err(PlayerError::Disconnected)));
+}
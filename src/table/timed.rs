@@ -0,0 +1,184 @@
+use std::future::{Future, poll_fn};
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+use crate::deck::Card;
+use crate::game::{Game, Seat};
+use crate::gamemode::Ruleset;
+use crate::player::{AsyncPlayer, NpcPlayer, Player, PlayerError, PlayerFuture};
+use crate::table::round::{Answer, Ask, Round};
+
+/// What happens when a seat runs out of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Fallback {
+    /// Decide for the seat: no Klopfen, Kontra or Re, pass in the auction,
+    /// and a card chosen by the [`NpcPlayer`] rules. A disconnected seat is
+    /// played this way as well.
+    AutoPlay,
+    /// End the round with [`PlayerError::Timeout`].
+    Forfeit,
+    /// Keep waiting for the seat without a limit.
+    Pause,
+}
+
+/// How long a seat may take for a single decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveLimit {
+    pub time: Duration,
+    pub fallback: Fallback,
+}
+
+/// [`play_round`](crate::table::play_round) for [`AsyncPlayer`]s, with an
+/// optional time limit on every decision.
+///
+/// Works with any executor; waiting for the limit uses a timer thread per
+/// decision rather than a runtime timer. The thread ends as soon as the
+/// decision is made.
+pub async fn play_round_async(
+    players: [&mut dyn AsyncPlayer; 4],
+    hands: [Vec<Card>; 4],
+    dealer: Seat,
+    ruleset: &Ruleset,
+    limit: Option<MoveLimit>,
+) -> Result<Option<Game>, PlayerError> {
+    let mut round = Round::new(hands, dealer, ruleset);
+    while let Some(ask) = round.ask() {
+        let answer = match ask {
+            Ask::Klopfen(seat, view) => {
+                Answer::Klopfen(decide(limit, seat, players[seat].klopfen(view), || false).await?)
+            }
+            Ask::Bid(seat, view, options) => {
+                Answer::Bid(decide(limit, seat, players[seat].bid(view, options), || None).await?)
+            }
+            Ask::Double(seat, view, call) => {
+                Answer::Double(decide(limit, seat, players[seat].double(view, call), || false).await?)
+            }
+            Ask::Card(seat, view, legal) => {
+                let auto = || NpcPlayer::new(seat as u32, "auto").play_card(&view, &legal).unwrap_or(legal[0]);
+                Answer::Card(decide(limit, seat, players[seat].play_card(view.clone(), legal.clone()), auto).await?)
+            }
+        };
+        round.answer(answer)?;
+    }
+    Ok(round.finish())
+}
+
+// Waits for `decision` within `limit`, falling back as configured
+async fn decide<T>(
+    limit: Option<MoveLimit>,
+    seat: Seat,
+    mut decision: PlayerFuture<'_, T>,
+    auto: impl FnOnce() -> T,
+) -> Result<T, PlayerError> {
+    let Some(limit) = limit else {
+        return decision.await;
+    };
+    let mut timer = pin!(Sleep::new(limit.time));
+    let first = poll_fn(|cx| match decision.as_mut().poll(cx) {
+        Poll::Ready(result) => Poll::Ready(Some(result)),
+        Poll::Pending => timer.as_mut().poll(cx).map(|_| None),
+    })
+    .await;
+
+    match (first, limit.fallback) {
+        (Some(Err(PlayerError::Disconnected)), Fallback::AutoPlay) => Ok(auto()),
+        (Some(result), _) => result,
+        (None, Fallback::AutoPlay) => Ok(auto()),
+        (None, Fallback::Forfeit) => Err(PlayerError::Timeout(seat)),
+        (None, Fallback::Pause) => decision.await,
+    }
+}
+
+// A timer completing after a fixed duration, driven by a parked thread.
+// Dropping it wakes the thread, which then ends without waiting out the
+// rest of the duration.
+struct Sleep {
+    state: Arc<Mutex<SleepState>>,
+    thread: Thread,
+}
+
+#[derive(Default)]
+struct SleepState {
+    done: bool,
+    cancelled: bool,
+    waker: Option<Waker>,
+}
+
+impl Sleep {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new(SleepState::default()));
+        let shared = Arc::clone(&state);
+        let deadline = Instant::now() + duration;
+        let handle = thread::spawn(move || {
+            loop {
+                if shared.lock().unwrap().cancelled {
+                    return;
+                }
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                thread::park_timeout(deadline - now);
+            }
+            let mut state = shared.lock().unwrap();
+            state.done = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Self { state, thread: handle.thread().clone() }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        self.state.lock().unwrap().cancelled = true;
+        self.thread.unpark();
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs `future` to completion on the current thread, for callers without
+/// an async runtime.
+///
+/// This is a convenience helper for tests, examples and command-line tools,
+/// not an executor: it polls a single future, parks the thread in between
+/// and spawns nothing. Servers and anything already running on a runtime
+/// such as tokio should await [`play_round_async`] there instead.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
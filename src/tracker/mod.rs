@@ -0,0 +1,206 @@
+//! Keeps count of the cards during play and what they reveal about the
+//! other hands.
+
+use std::sync::Arc;
+
+use crate::deck::{Card, Deck, Suit};
+use crate::game::{PlayerView, Seat, Trick};
+use crate::gamemode::GameRules;
+
+/// The suit a card counts as in a trick: all trumps form a suit of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrickSuit {
+    Trump,
+    Plain(Suit),
+}
+
+/// Follows the cards as they are played.
+///
+/// A seat that does not follow the led suit (or trump) is known to hold no
+/// more cards of it. In a Sauspiel the declarer never holds the called Sau,
+/// and neither does a seat that played another card of its suit when the
+/// suit was led, since the Sau would have had to be played. That no longer
+/// holds once the suit has been led before: its holder may have run away
+/// (davonlaufen) and kept the Sau.
+#[derive(Clone)]
+pub struct Tracker {
+    rules: Arc<dyn GameRules>,
+    declarer: Option<Seat>,
+    partner: Option<Seat>,
+    remaining: Vec<Card>,
+    voids: [Vec<TrickSuit>; 4],
+    without_called: [bool; 4],
+    // Whether a finished trick was led in the called suit
+    called_led: bool,
+    trick: Vec<(Seat, Card)>,
+    points: [u8; 4],
+    tricks_won: [usize; 4],
+}
+
+impl Tracker {
+    pub fn new(rules: impl GameRules + 'static, declarer: Option<Seat>) -> Self {
+        Self::with_rules(Arc::new(rules), declarer)
+    }
+
+    pub fn with_rules(rules: Arc<dyn GameRules>, declarer: Option<Seat>) -> Self {
+        let mut without_called = [false; 4];
+        if let Some(seat) = declarer.filter(|_| rules.called_card().is_some()) {
+            without_called[seat] = true;
+        }
+        Self {
+            rules,
+            declarer,
+            partner: None,
+            remaining: Deck::new().iter().copied().collect(),
+            voids: Default::default(),
+            without_called,
+            called_led: false,
+            trick: Vec::new(),
+            points: [0; 4],
+            tricks_won: [0; 4],
+        }
+    }
+
+    /// A tracker that has seen everything `view` has, `None` during bidding.
+    ///
+    /// The partner is taken over if the view knows it.
+    pub fn from_view(view: &PlayerView) -> Option<Self> {
+        let mut tracker = Self::with_rules(view.shared_rules()?, view.declarer());
+        for (seat, card) in view.played_cards() {
+            tracker.play(seat, card);
+        }
+        if let Some(partner) = view.partner() {
+            tracker.set_partner(partner);
+        }
+        Some(tracker)
+    }
+
+    pub fn rules(&self) -> &dyn GameRules {
+        self.rules.as_ref()
+    }
+
+    pub fn suit_of(&self, card: &Card) -> TrickSuit {
+        if self.rules.is_trump(card) { TrickSuit::Trump } else { TrickSuit::Plain(card.suit) }
+    }
+
+    /// Records `card` played by `seat`; the card is assumed to be legal.
+    pub fn play(&mut self, seat: Seat, card: Card) {
+        let Some(i) = self.remaining.iter().position(|&c| c == card) else {
+            return;
+        };
+        self.remaining.remove(i);
+
+        if let Some(&(_, led)) = self.trick.first() {
+            if !self.rules.follows(&card, &led) {
+                let suit = self.suit_of(&led);
+                if !self.voids[seat].contains(&suit) {
+                    self.voids[seat].push(suit);
+                }
+            } else if let Some(sau) = self.rules.called_card()
+                && self.rules.follows(&led, &sau)
+                && !self.called_led
+                && card != sau
+                && self.remaining.contains(&sau)
+            {
+                self.without_called[seat] = true;
+            }
+        }
+        if self.rules.called_card() == Some(card) && Some(seat) != self.declarer {
+            self.partner = Some(seat);
+        }
+
+        self.trick.push((seat, card));
+        if self.trick.len() == 4 {
+            let cards: Vec<Card> = self.trick.iter().map(|&(_, c)| c).collect();
+            if let Some(sau) = self.rules.called_card() {
+                self.called_led |= self.rules.follows(&cards[0], &sau);
+            }
+            let (winner, _) = self.trick[self.rules.winning_index(&cards)];
+            self.points[winner] += cards.iter().map(|c| self.rules.card_points(c)).sum::<u8>();
+            self.tricks_won[winner] += 1;
+            self.trick.clear();
+        }
+    }
+
+    /// Records every card of `trick` in order.
+    pub fn play_trick(&mut self, trick: &Trick) {
+        for (seat, card) in trick.plays() {
+            self.play(seat, card);
+        }
+    }
+
+    /// Names the declarer's partner, for a seat that knows it from its own hand.
+    pub fn set_partner(&mut self, seat: Seat) {
+        self.partner = Some(seat);
+    }
+
+    /// Cards not played yet, in deck order.
+    pub fn remaining(&self) -> &[Card] {
+        &self.remaining
+    }
+
+    /// Trumps not played yet, strongest first.
+    pub fn remaining_trumps(&self) -> Vec<Card> {
+        self.rules.trump_order().into_iter().filter(|c| self.remaining.contains(c)).collect()
+    }
+
+    /// Cards not played yet and not in `hand`.
+    pub fn unseen(&self, hand: &[Card]) -> Vec<Card> {
+        self.remaining.iter().copied().filter(|c| !hand.contains(c)).collect()
+    }
+
+    /// Whether `seat` has shown that it holds no more cards of `suit`.
+    pub fn is_void(&self, seat: Seat, suit: TrickSuit) -> bool {
+        self.voids[seat].contains(&suit)
+    }
+
+    /// Whether `seat` has shown that it holds no more trumps.
+    pub fn is_trump_free(&self, seat: Seat) -> bool {
+        self.is_void(seat, TrickSuit::Trump)
+    }
+
+    /// Whether `seat` may still hold `card` as far as the play has shown.
+    pub fn may_hold(&self, seat: Seat, card: &Card) -> bool {
+        self.remaining.contains(card)
+            && !self.is_void(seat, self.suit_of(card))
+            && !(self.without_called[seat] && self.rules.called_card() == Some(*card))
+    }
+
+    /// The declarer's partner, once the called Sau has been played or it
+    /// was named with [`Tracker::set_partner`].
+    pub fn partner(&self) -> Option<Seat> {
+        self.partner
+    }
+
+    /// The cards of the unfinished trick.
+    pub fn current_trick(&self) -> &[(Seat, Card)] {
+        &self.trick
+    }
+
+    /// Augen taken by `seat` in completed tricks.
+    pub fn points(&self, seat: Seat) -> u8 {
+        self.points[seat]
+    }
+
+    pub fn tricks_won(&self, seat: Seat) -> usize {
+        self.tricks_won[seat]
+    }
+
+    /// Augen taken by the declaring side and by the other side, once it is
+    /// known who plays together.
+    pub fn party_points(&self) -> Option<(u8, u8)> {
+        let declarer = self.declarer?;
+        let partner = match self.rules.called_card() {
+            Some(_) => Some(self.partner?),
+            None => None,
+        };
+        let side = |s: Seat| s == declarer || Some(s) == partner;
+        let declarer_points = (0..4).filter(|&s| side(s)).map(|s| self.points[s]).sum();
+        let other_points = (0..4).filter(|&s| !side(s)).map(|s| self.points[s]).sum();
+        Some((declarer_points, other_points))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use crate::deck::Rank;
+use crate::game::Game;
+use crate::gamemode::Gamemode;
+
+fn card(suit: Suit, rank: Rank) -> Card {
+    Card { suit, rank }
+}
+
+#[test]
+fn tracks_remaining_cards_voids_and_points() {
+    let mut tracker = Tracker::new(Gamemode::Solo(Suit::Herz), Some(0));
+    tracker.play(0, card(Suit::Herz, Rank::Ober));
+    tracker.play(1, card(Suit::Herz, Rank::Sieben));
+    tracker.play(2, card(Suit::Gras, Rank::Ass));
+    assert_eq!(tracker.current_trick().len(), 3);
+    tracker.play(3, card(Suit::Eichel, Rank::Unter));
+
+    assert!(tracker.current_trick().is_empty());
+    assert_eq!(tracker.remaining().len(), 28);
+    assert_eq!(tracker.remaining_trumps().len(), 11);
+    assert_eq!(tracker.remaining_trumps()[0], card(Suit::Eichel, Rank::Ober));
+    assert!(tracker.is_trump_free(2));
+    assert!(!tracker.is_trump_free(1));
+    assert!(!tracker.may_hold(2, &card(Suit::Herz, Rank::Koenig)));
+    assert!(tracker.may_hold(2, &card(Suit::Gras, Rank::Koenig)));
+    assert!(!tracker.may_hold(1, &card(Suit::Herz, Rank::Ober)));
+
+    assert_eq!(tracker.points(0), 16);
+    assert_eq!(tracker.tricks_won(0), 1);
+    assert_eq!(tracker.party_points(), Some((16, 0)));
+}
+
+#[test]
+fn infers_who_holds_the_called_sau() {
+    let sau = card(Suit::Gras, Rank::Ass);
+    let mut tracker = Tracker::new(Gamemode::Sauspiel(Suit::Gras), Some(0));
+    assert!(!tracker.may_hold(0, &sau));
+    assert_eq!(tracker.party_points(), None);
+
+    tracker.play(0, card(Suit::Gras, Rank::Sieben));
+    tracker.play(1, card(Suit::Gras, Rank::Acht));
+    assert!(!tracker.may_hold(1, &sau));
+    assert!(tracker.may_hold(2, &sau));
+
+    tracker.play(2, sau);
+    tracker.play(3, card(Suit::Eichel, Rank::Sieben));
+    assert_eq!(tracker.partner(), Some(2));
+    assert!(tracker.is_void(3, TrickSuit::Plain(Suit::Gras)));
+    assert!(!tracker.is_void(3, TrickSuit::Trump));
+    assert_eq!(tracker.party_points(), Some((11, 0)));
+}
+
+#[test]
+fn a_holder_who_ran_away_may_still_hold_the_sau() {
+    let sau = card(Suit::Schell, Rank::Ass);
+    let mut tracker = Tracker::new(Gamemode::Sauspiel(Suit::Schell), Some(0));
+
+    // Seat 1 runs away with the Schell Neun and keeps the Sau
+    tracker.play(1, card(Suit::Schell, Rank::Neun));
+    tracker.play(2, card(Suit::Schell, Rank::Zehn));
+    tracker.play(3, card(Suit::Herz, Rank::Ober));
+    tracker.play(0, card(Suit::Schell, Rank::Sieben));
+
+    tracker.play(3, card(Suit::Schell, Rank::Koenig));
+    tracker.play(0, card(Suit::Gras, Rank::Sieben));
+    tracker.play(1, card(Suit::Schell, Rank::Acht));
+    assert!(tracker.may_hold(1, &sau));
+}
+
+#[test]
+fn from_view_agrees_with_the_game() {
+    let mut game = Game::new(Gamemode::Wenz(None), Deck::new().deal_4x8().unwrap(), 0, Some(3));
+    for _ in 0..14 {
+        let card = game.legal_cards()[0];
+        game.play(card).unwrap();
+    }
+
+    let view = game.view(1);
+    let tracker = Tracker::from_view(&view).unwrap();
+    assert_eq!(tracker.unseen(view.hand()), view.unseen_cards());
+    assert_eq!(tracker.current_trick().len(), 2);
+    for seat in 0..4 {
+        assert_eq!(tracker.points(seat), game.points(seat));
+        assert_eq!(tracker.tricks_won(seat), game.tricks_won(seat));
+        for card in game.hand(seat) {
+            assert!(tracker.may_hold(seat, card), "seat {} holds {}", seat, card);
+        }
+    }
+}